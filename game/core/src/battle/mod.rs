@@ -1,2 +1,4 @@
 pub mod pve;
+pub mod pvp;
+pub mod stream;
 pub mod traits;
@@ -1,13 +1,15 @@
 extern crate alloc;
 use alloc::{vec, vec::Vec};
 use rand::RngCore;
+use spore_warriors_generated as generated;
 
-use crate::battle::pve::{FightView, Instruction, MapBattlePVE};
+use crate::battle::pve::{Continuation, FightView, Instruction, MapBattlePVE};
 use crate::battle::traits::{FightLog, IterationOutput};
-use crate::contexts::CtxAdaptor;
+use crate::contexts::{CtxAdaptor, EnemyContext};
 use crate::errors::Error;
-use crate::systems::{Command, SystemController, SystemInput, SystemReturn};
-use crate::wrappings::RequireTarget;
+use crate::map::{apply_enemy_difficulty, apply_enemy_modifiers};
+use crate::systems::{Command, SystemController, SystemInput, SystemObjects, SystemReturn};
+use crate::wrappings::{Enemy, RequireTarget, CARD_KEYWORD_INNATE, CARD_OFFSET_START};
 
 impl MapBattlePVE {
     pub(super) fn player_draw(
@@ -35,6 +37,22 @@ impl MapBattlePVE {
         Ok(())
     }
 
+    pub(super) fn player_draw_innate(&mut self) -> Result<u8, Error> {
+        let mut count = 0;
+        while let Some(card_index) = self
+            .player_deck
+            .deck
+            .iter()
+            .position(|v| v.card.has_keyword(CARD_KEYWORD_INNATE))
+        {
+            let card = self.player_deck.deck.remove(card_index);
+            self.trigger_log(FightLog::Draw(card.offset()))?;
+            self.player_deck.hand_deck.push(card);
+            count += 1;
+        }
+        Ok(count)
+    }
+
     pub(super) fn player_select_draw(&mut self, card_offsets: Vec<usize>) -> Result<(), Error> {
         for offset in card_offsets {
             if !self.player_deck.selection_pool.contains(&offset) {
@@ -116,6 +134,61 @@ impl MapBattlePVE {
         Ok(())
     }
 
+    // summoned enemies take the next offsets, which must stay below card offsets, and only
+    // the ones within the alive cap are randomized, so the dropped ones never consume rng
+    pub(super) fn summon_enemies(
+        &mut self,
+        enemy: generated::Enemy,
+        count: u16,
+        cap: u16,
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        let alive_count = self.opponents.iter().filter(|v| v.hp > 0).count();
+        let free_offsets = (CARD_OFFSET_START - 1).saturating_sub(self.opponents.len());
+        let count = (cap as usize)
+            .saturating_sub(alive_count)
+            .min(free_offsets)
+            .min(count as usize);
+        for _ in 0..count {
+            let offset = self.opponents.len() + 1;
+            let mut enemy = Enemy::randomized(
+                &controller.resource_pool,
+                enemy.clone(),
+                &mut controller.rng,
+            )?;
+            apply_enemy_difficulty(&mut enemy, &self.difficulty_rules);
+            apply_enemy_modifiers(&mut enemy, &self.modifiers);
+            self.opponents.push(EnemyContext::new(enemy, offset));
+            self.trigger_log(FightLog::SummonEnemy(offset))?;
+        }
+        if count > 0 {
+            self.trigger_log(FightLog::Snapshot(
+                self.player.clone(),
+                self.opponents.clone(),
+            ))?;
+        }
+        Ok(())
+    }
+
+    // the next wave replaces the cleared one, and actions of the cleared enemies are dropped
+    fn next_wave(&mut self, enemies: Vec<Enemy>) -> Result<(), Error> {
+        self.wave += 1;
+        self.opponents = enemies
+            .into_iter()
+            .enumerate()
+            .map(|(i, enemy)| EnemyContext::new(enemy, i + 1))
+            .collect();
+        self.pending_instructions
+            .retain(|v| v.view != FightView::Enemy);
+        self.continuations
+            .retain(|v| !matches!(v, Continuation::EnemyAction(_, _)));
+        self.trigger_log(FightLog::EnemyWave(self.wave))?;
+        self.trigger_log(FightLog::Snapshot(
+            self.player.clone(),
+            self.opponents.clone(),
+        ))
+    }
+
     pub(super) fn collect_system_caster_offset(
         &self,
         view: FightView,
@@ -150,14 +223,25 @@ impl MapBattlePVE {
                     .ok_or(Error::BattleInvalidCardOffsetToRefer)?;
                 targets.push(card.offset());
             }
-            (FightView::Player, RequireTarget::Opponent)
-            | (FightView::Enemy, RequireTarget::Owner) => {
+            // player selects the enemy by index, while enemy view carries the enemy offset
+            (FightView::Player, RequireTarget::Opponent) => {
+                let Some(index) = target_offset else {
+                    return Err(Error::BattleTargetOffsetError);
+                };
+                let enemy = self
+                    .opponents
+                    .get(index)
+                    .ok_or(Error::BattleEnemyNotFound)?;
+                targets.push(enemy.offset());
+            }
+            (FightView::Enemy, RequireTarget::Owner) => {
                 let Some(offset) = target_offset else {
                     return Err(Error::BattleTargetOffsetError);
                 };
                 let enemy = self
                     .opponents
-                    .get_mut(offset)
+                    .iter()
+                    .find(|v| v.offset() == offset)
                     .ok_or(Error::BattleEnemyNotFound)?;
                 targets.push(enemy.offset());
             }
@@ -178,7 +262,39 @@ impl MapBattlePVE {
                 let enemy = self.opponents.get_mut(offset).unwrap();
                 targets.push(enemy.offset());
             }
+            // allies of enemy are the alive enemies, including the caster itself
+            (FightView::Enemy, RequireTarget::AllAllies) => {
+                self.opponents
+                    .iter()
+                    .filter(|v| v.hp > 0)
+                    .for_each(|v| targets.push(v.offset()));
+            }
+            (FightView::Enemy, RequireTarget::RandomAlly) => {
+                let allies = self
+                    .opponents
+                    .iter()
+                    .filter(|v| v.hp > 0)
+                    .map(|v| v.offset())
+                    .collect::<Vec<_>>();
+                if !allies.is_empty() {
+                    let index = controller.rng.next_u32() as usize % allies.len();
+                    targets.push(allies[index]);
+                }
+            }
+            (FightView::Enemy, RequireTarget::LowestHpAlly) => {
+                if let Some(enemy) = self
+                    .opponents
+                    .iter()
+                    .filter(|v| v.hp > 0)
+                    .min_by_key(|v| v.hp)
+                {
+                    targets.push(enemy.offset());
+                }
+            }
             (FightView::Player, RequireTarget::Owner)
+            | (FightView::Player, RequireTarget::RandomAlly)
+            | (FightView::Player, RequireTarget::AllAllies)
+            | (FightView::Player, RequireTarget::LowestHpAlly)
             | (FightView::Enemy, RequireTarget::Opponent)
             | (FightView::Enemy, RequireTarget::RandomOpponent)
             | (FightView::Enemy, RequireTarget::AllOpponents) => targets.push(self.player.offset()),
@@ -186,58 +302,157 @@ impl MapBattlePVE {
         Ok(targets)
     }
 
+    fn collect_system_objects(
+        &mut self,
+        view: FightView,
+        caster: usize,
+        source: Option<usize>,
+        targets: &[usize],
+    ) -> Result<SystemObjects, Error> {
+        let mut objects: Vec<&mut dyn CtxAdaptor> = vec![&mut self.player];
+        self.opponents.iter_mut().for_each(|v| objects.push(v));
+        if let FightView::Card(offset) = view {
+            let card = self
+                .player_deck
+                .refer_card(offset)
+                .ok_or(Error::BattleInvalidCardOffsetToRefer)?;
+            objects.push(card);
+        }
+        SystemObjects::with_source(caster, source, targets, objects)
+    }
+
     pub(super) fn operate_pending_instructions(
         &mut self,
         controller: &mut SystemController,
     ) -> Result<IterationOutput, Error> {
-        let mut game_over = false;
-        self.last_output = IterationOutput::Continue;
-        while let Some(Instruction {
+        if !self.game_over {
+            self.last_output = IterationOutput::Continue;
+        }
+        if self.step_mode {
+            return Ok(self.last_output);
+        }
+        while self.operate_next_step(controller)? {
+            if let IterationOutput::RequireCardSelect(_, _) = self.last_output {
+                break;
+            }
+        }
+        Ok(self.last_output)
+    }
+
+    // continuations only run after all instructions triggered before them are settled
+    pub(super) fn operate_next_step(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<bool, Error> {
+        if let Some(instruction) = self.pending_instructions.pop_front() {
+            self.operate_instruction(instruction, controller)?;
+            return Ok(true);
+        }
+        let Some(continuation) = self.continuations.pop_front() else {
+            if self.game_over {
+                self.player.reset();
+            }
+            return Ok(false);
+        };
+        if !self.game_over {
+            match continuation {
+                Continuation::EnemyAction(offset, effects) => self.enemy_action(offset, effects)?,
+                Continuation::RoundStart => self.round_start(controller)?,
+            }
+        }
+        Ok(true)
+    }
+
+    fn operate_instruction(
+        &mut self,
+        instruction: Instruction,
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        let Instruction {
             view,
             ctx,
             target,
+            owner,
             mut system_input,
-        }) = self.pending_instructions.pop_front()
-        {
-            let target_type = ctx.system.target_type;
-            let caster = self.collect_system_caster_offset(view, target_type, target)?;
-            self.trigger_log(FightLog::CallSystem(caster, ctx.clone()))?;
-            let targets =
-                self.collect_system_target_offsets(view, target_type, target, controller)?;
-            let mut objects: Vec<&mut dyn CtxAdaptor> = vec![&mut self.player];
-            self.opponents.iter_mut().for_each(|v| objects.push(v));
-            self.player_deck
-                .collect_cards()
-                .into_iter()
-                .for_each(|v| objects.push(v));
-            if game_over {
-                system_input = Some(SystemInput::Trigger(FightLog::GameOver));
-            }
-            let system_return =
-                controller.system_call(ctx, caster, targets, &mut objects, system_input.clone())?;
-            if !game_over {
-                if self.player.hp == 0 {
-                    self.last_output = IterationOutput::GameLose;
-                    self.trigger_log(FightLog::GameOver)?;
-                    game_over = true;
-                    continue;
-                }
-                if self.opponents.iter().all(|v| v.hp == 0) {
-                    self.last_output = IterationOutput::GameWin;
-                    self.trigger_log(FightLog::GameOver)?;
-                    game_over = true;
-                    continue;
-                }
-                self.operate_system_return(system_return, view, controller)?;
-                if let IterationOutput::RequireCardSelect(_, _) = self.last_output {
-                    break;
+        } = instruction;
+        let target_type = ctx.system.target_type;
+        let caster = match owner {
+            Some(owner) => owner,
+            None => self.collect_system_caster_offset(view, target_type, target)?,
+        };
+        self.trigger_log(FightLog::CallSystem(caster, ctx.clone()))?;
+        // a mounted system resolves to its owner, along with the targets it recorded
+        let targets = match owner {
+            Some(owner) => [owner].into_iter().chain(ctx.register_3.clone()).collect(),
+            None => self.collect_system_target_offsets(view, target_type, target, controller)?,
+        };
+        if self.game_over {
+            system_input = Some(SystemInput::Trigger(FightLog::GameOver));
+        }
+        let source = match &system_input {
+            Some(SystemInput::Trigger(FightLog::SystemDamage(source, _, _))) => Some(*source),
+            _ => None,
+        };
+        let observed = if self.observers.is_empty() {
+            None
+        } else {
+            self.observers
+                .iter_mut()
+                .for_each(|observer| observer.on_system_call(caster, &ctx, &targets));
+            Some((ctx.clone(), self.battle_state()))
+        };
+        let mut objects = self.collect_system_objects(view, caster, source, &targets)?;
+        let system_return = controller.system_call(ctx, &mut objects, system_input.clone())?;
+        if let Some((ctx, before)) = observed {
+            let after = self.battle_state();
+            self.observers
+                .iter_mut()
+                .for_each(|observer| observer.on_state_change(&ctx, &before, &after));
+        }
+        // summoned enemies join before settlement, so the fight won't be won in advance
+        let system_return = match system_return {
+            SystemReturn::Continue(cmds) if !self.game_over => {
+                let (summons, cmds): (Vec<_>, Vec<_>) = cmds
+                    .into_iter()
+                    .partition(|v| matches!(v, Command::SummonEnemies(_, _, _)));
+                for cmd in summons {
+                    if let Command::SummonEnemies(enemy, count, cap) = cmd {
+                        self.summon_enemies(enemy, count, cap, controller)?;
+                    }
                 }
+                SystemReturn::Continue(cmds)
             }
+            system_return => system_return,
+        };
+        let phase_changes = self
+            .opponents
+            .iter_mut()
+            .filter(|v| v.hp > 0)
+            .filter_map(|v| v.update_phase().map(|phase| (v.offset(), phase)))
+            .collect::<Vec<_>>();
+        for (offset, phase) in phase_changes {
+            self.trigger_log(FightLog::EnemyPhaseChange(offset, phase))?;
         }
-        if game_over {
-            self.player.reset();
+        if self.game_over {
+            return Ok(());
         }
-        Ok(self.last_output)
+        if self.player.hp == 0 {
+            self.last_output = IterationOutput::GameLose;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(());
+        }
+        if self.opponents.iter().all(|v| v.hp == 0) {
+            if let Some(enemies) = self.enemy_waves.pop_front() {
+                self.next_wave(enemies)?;
+                return self.operate_system_return(system_return, view, controller);
+            }
+            self.last_output = IterationOutput::GameWin;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(());
+        }
+        self.operate_system_return(system_return, view, controller)
     }
 
     fn operate_system_return(
@@ -255,6 +470,7 @@ impl MapBattlePVE {
                 }
                 self.last_output =
                     IterationOutput::RequireCardSelect(select_count, draw_or_discard);
+                self.checkpoints.clear();
                 if let Some(mut changer) = operator {
                     let logs = changer(&mut self.player_deck);
                     return_cmds = vec![Command::AddLogs(logs)];
@@ -267,11 +483,18 @@ impl MapBattlePVE {
         };
         for cmd in return_cmds {
             match cmd {
-                Command::AddLogs(mut logs) => self.fight_logs.append(&mut logs),
+                Command::AddLogs(logs) => {
+                    logs.into_iter()
+                        .map(|log| self.trigger_log(log))
+                        .collect::<Result<Vec<_>, _>>()?;
+                }
                 Command::DrawCards(count) => self.player_draw(count, controller)?,
                 Command::DiscardHandCards(count, to_grave) => {
                     self.player_random_discard(count, to_grave, controller)?
                 }
+                Command::SummonEnemies(enemy, count, cap) => {
+                    self.summon_enemies(enemy, count, cap, controller)?
+                }
             }
         }
         Ok(())
@@ -1,12 +1,12 @@
 extern crate alloc;
 use alloc::vec::Vec;
 
-use crate::battle::pve::{FightView, Instruction, MapBattlePVE};
+use crate::battle::pve::{Checkpoint, Continuation, FightView, Instruction, MapBattlePVE};
 use crate::battle::traits::{FightLog, IterationInput, IterationOutput, Selection};
 use crate::contexts::CtxAdaptor;
 use crate::errors::Error;
 use crate::systems::SystemController;
-use crate::wrappings::System;
+use crate::wrappings::{System, CARD_KEYWORD_ETHEREAL, CARD_KEYWORD_EXHAUST, CARD_KEYWORD_RETAIN};
 
 impl MapBattlePVE {
     pub(super) fn iterate(
@@ -15,23 +15,68 @@ impl MapBattlePVE {
         controller: &mut SystemController,
     ) -> Result<IterationOutput, Error> {
         match operation {
-            IterationInput::ItemUse(Selection::Item(item_index), offset) => {
-                self.iterate_item_use(item_index, offset, controller)
-            }
-            IterationInput::HandCardUse(Selection::SingleCard(card_index), enemy_offset) => {
-                self.iterate_hand_card_use(card_index, enemy_offset, controller)
-            }
+            IterationInput::ItemUse(Selection::Item(item_index), offset) => self
+                .player_action(controller, |battle, controller| {
+                    battle.iterate_item_use(item_index, offset, controller)
+                }),
+            IterationInput::HandCardUse(Selection::SingleCard(card_index), enemy_offset) => self
+                .player_action(controller, |battle, controller| {
+                    battle.iterate_hand_card_use(card_index, enemy_offset, controller)
+                }),
             IterationInput::PendingCardSelect(Selection::MultiCards(card_indexes)) => {
                 self.iterate_pending_card_select(card_indexes, controller)
             }
-            IterationInput::SpecialCardUse(offset) => {
-                self.iterate_special_card_use(offset, controller)
-            }
+            IterationInput::SpecialCardUse(offset) => self
+                .player_action(controller, |battle, controller| {
+                    battle.iterate_special_card_use(offset, controller)
+                }),
             IterationInput::EnemyTurn => self.iterate_enemy_turn(controller),
             _ => Err(Error::BattleInvalidIterationOperation),
         }
     }
 
+    // player actions are limited in each turn, and can be undone until any hidden
+    // information is revealed
+    fn player_action(
+        &mut self,
+        controller: &mut SystemController,
+        operate: impl FnOnce(&mut Self, &mut SystemController) -> Result<IterationOutput, Error>,
+    ) -> Result<IterationOutput, Error> {
+        if self.turn_actions >= self.max_turn_actions {
+            return Err(Error::BattleTurnActionsExceeded);
+        }
+        if !self.is_settled() {
+            self.checkpoints.clear();
+            let output = operate(self, controller)?;
+            self.turn_actions += 1;
+            return Ok(output);
+        }
+        self.checkpoints.push(Checkpoint {
+            player: self.player.clone(),
+            player_deck: self.player_deck.clone(),
+            opponents: self.opponents.clone(),
+            last_output: self.last_output,
+            turn_actions: self.turn_actions,
+            rng: controller.rng.clone(),
+        });
+        let checkpoint_count = self.checkpoints.len();
+        let log_count = self.fight_logs.len();
+        let output = operate(self, controller);
+        // a failed action may be half applied, so roll it back while its checkpoint is alive
+        if output.is_err() && self.checkpoints.len() == checkpoint_count {
+            if let Some(checkpoint) = self.checkpoints.pop() {
+                self.restore(checkpoint, controller);
+                self.fight_logs.truncate(log_count);
+                self.pending_instructions.clear();
+                self.continuations.clear();
+            }
+        }
+        if output.is_ok() {
+            self.turn_actions += 1;
+        }
+        output
+    }
+
     pub(super) fn trigger_iteration_systems(
         &mut self,
         view: FightView,
@@ -46,6 +91,7 @@ impl MapBattlePVE {
                     view,
                     ctx: system.into(),
                     target,
+                    owner: None,
                     system_input: None,
                 });
                 Ok(())
@@ -88,6 +134,9 @@ impl MapBattlePVE {
         if IterationOutput::Continue != self.last_output {
             return Err(Error::BattleUnexpectedLastOutput);
         };
+        if self.player_deck.hand_deck[card_index].is_frozen() {
+            return Err(Error::BattleCardFrozen);
+        }
         let context = self.player_deck.hand_deck.remove(card_index);
         if self.player.power < context.power_cost {
             return Err(Error::BattlePowerInsufficient);
@@ -95,7 +144,12 @@ impl MapBattlePVE {
         let systems = context.card.system_pool.clone();
         self.trigger_log(FightLog::PowerCost(context.power_cost))?;
         self.trigger_log(FightLog::HandCardUse(context.offset()))?;
-        self.player_deck.grave_deck.push(context);
+        if context.card.has_keyword(CARD_KEYWORD_EXHAUST) {
+            self.trigger_log(FightLog::ExhaustCard(context.offset()))?;
+            self.player_deck.unavaliable_deck.push(context);
+        } else {
+            self.player_deck.grave_deck.push(context);
+        }
         self.trigger_iteration_systems(FightView::Player, systems, target, controller)
     }
 
@@ -110,6 +164,9 @@ impl MapBattlePVE {
         if self.player_deck.special_use_count >= self.player_deck.special_max_count {
             return Err(Error::BattleUseCountInsufficient);
         }
+        if self.player.is_silenced() {
+            return Err(Error::BattleSpecialCardSilenced);
+        }
         let cost = self.player_deck.special_card.power_cost;
         if self.player.power < cost {
             return Err(Error::BattlePowerInsufficient);
@@ -155,34 +212,84 @@ impl MapBattlePVE {
         &mut self,
         controller: &mut SystemController,
     ) -> Result<IterationOutput, Error> {
-        if !self.pending_instructions.is_empty() {
+        if !self.pending_instructions.is_empty() || !self.continuations.is_empty() {
             return Err(Error::BattleInstructionNotEmpty);
         }
-        let mut remained_hand_cards = self.player_deck.hand_deck.drain(..).collect::<Vec<_>>();
-        self.player_deck.grave_deck.append(&mut remained_hand_cards);
+        self.checkpoints.clear();
+        let (retained_hand_cards, remained_hand_cards): (Vec<_>, Vec<_>) = self
+            .player_deck
+            .hand_deck
+            .drain(..)
+            .partition(|v| v.card.has_keyword(CARD_KEYWORD_RETAIN));
+        self.player_deck.hand_deck = retained_hand_cards;
+        for context in remained_hand_cards {
+            if context.card.has_keyword(CARD_KEYWORD_ETHEREAL) {
+                self.trigger_log(FightLog::ExhaustCard(context.offset()))?;
+                self.player_deck.unavaliable_deck.push(context);
+            } else {
+                self.player_deck.grave_deck.push(context);
+            }
+        }
         self.trigger_log(FightLog::DiscardAllHandDeck)?;
         self.trigger_log(FightLog::EnemyTurn(self.round))?;
 
         let actions = self
             .opponents
             .iter_mut()
-            .map(|enemy| enemy.pop_action(&mut controller.rng))
+            .map(|enemy| {
+                if enemy.is_stunned() {
+                    Ok(None)
+                } else {
+                    enemy
+                        .pop_action(&self.player, self.round, &mut controller.rng)
+                        .map(Some)
+                }
+            })
             .collect::<Result<Vec<_>, _>>()?;
-        for (offset, effects) in actions.into_iter().enumerate() {
-            let output = self.trigger_iteration_systems(
-                FightView::Enemy,
-                effects,
-                Some(offset),
-                controller,
-            )?;
-            if IterationOutput::GameWin == output || IterationOutput::GameLose == output {
-                return Ok(output);
-            }
+        for (index, effects) in actions.into_iter().enumerate() {
+            let offset = self.opponents[index].offset();
+            self.continuations
+                .push_back(Continuation::EnemyAction(offset, effects));
         }
-        if !self.pending_instructions.is_empty() {
-            return Err(Error::BattleInstructionNotEmpty);
+        self.continuations.push_back(Continuation::RoundStart);
+
+        let output = self.operate_pending_instructions(controller)?;
+        if IterationOutput::Continue == output && self.continuations.is_empty() {
+            Ok(IterationOutput::PlayerTurn)
+        } else {
+            Ok(output)
+        }
+    }
+
+    pub(super) fn enemy_action(
+        &mut self,
+        offset: usize,
+        effects: Option<Vec<System>>,
+    ) -> Result<(), Error> {
+        let Some(effects) = effects else {
+            return self.trigger_log(FightLog::EnemyStunned(offset));
+        };
+        effects.into_iter().for_each(|system| {
+            self.pending_instructions.push_back(Instruction {
+                view: FightView::Enemy,
+                ctx: system.into(),
+                target: Some(offset),
+                owner: None,
+                system_input: None,
+            });
+        });
+        Ok(())
+    }
+
+    pub(super) fn round_start(&mut self, controller: &mut SystemController) -> Result<(), Error> {
+        if self.round >= self.max_round {
+            self.last_output = IterationOutput::GameTimeout;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(());
         }
         self.round += 1;
+        self.turn_actions = 0;
         self.player.round_reset();
         self.trigger_log(FightLog::RecoverPower)?;
         self.trigger_log(FightLog::RecoverCardCost)?;
@@ -194,12 +301,6 @@ impl MapBattlePVE {
             self.player.clone(),
             self.opponents.iter().map(|v| v.clone()).collect(),
         ))?;
-
-        let output = self.operate_pending_instructions(controller)?;
-        if IterationOutput::Continue == output {
-            Ok(IterationOutput::PlayerTurn)
-        } else {
-            Ok(output)
-        }
+        Ok(())
     }
 }
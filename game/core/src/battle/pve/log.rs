@@ -9,10 +9,17 @@ use crate::systems::SystemInput;
 
 impl MapBattlePVE {
     pub(super) fn trigger_log(&mut self, log: FightLog) -> Result<(), Error> {
+        if let FightLog::Draw(_)
+        | FightLog::RecoverGraveDeck
+        | FightLog::EnemyWave(_)
+        | FightLog::GameOver = log
+        {
+            self.checkpoints.clear();
+        }
         self.trigger_mounting_systems(
             FightView::Player,
             self.player.mounting_systems.clone(),
-            None,
+            self.player.offset(),
             log.clone(),
         )?;
         self.opponents
@@ -21,7 +28,7 @@ impl MapBattlePVE {
             .collect::<Vec<_>>()
             .into_iter()
             .map(|(offset, contexts)| {
-                self.trigger_mounting_systems(FightView::Enemy, contexts, Some(offset), log.clone())
+                self.trigger_mounting_systems(FightView::Enemy, contexts, offset, log.clone())
             })
             .collect::<Result<Vec<_>, _>>()?;
         self.player_deck
@@ -31,11 +38,14 @@ impl MapBattlePVE {
                 self.trigger_mounting_systems(
                     FightView::Card(card_offset),
                     system_offsets,
-                    None,
+                    card_offset,
                     log.clone(),
                 )
             })
             .collect::<Result<Vec<_>, _>>()?;
+        self.observers
+            .iter_mut()
+            .for_each(|observer| observer.on_log(&log));
         self.fight_logs.push(log);
         Ok(())
     }
@@ -44,15 +54,21 @@ impl MapBattlePVE {
         &mut self,
         view: FightView,
         contexts: Vec<SystemContext>,
-        target: Option<usize>,
+        owner: usize,
         log: FightLog,
     ) -> Result<(), Error> {
+        // system calls are only recorded, otherwise mounted systems would trigger each other
+        // endlessly by their own calls
+        if let FightLog::CallSystem(_, _) = log {
+            return Ok(());
+        }
         contexts
             .into_iter()
             .map(|ctx| {
                 let system_input = SystemInput::Trigger(log.clone());
                 self.pending_instructions.push_back(Instruction {
-                    target,
+                    target: None,
+                    owner: Some(owner),
                     ctx,
                     view,
                     system_input: Some(system_input),
@@ -1,17 +1,25 @@
 extern crate alloc;
+use alloc::boxed::Box;
 use alloc::collections::VecDeque;
 use alloc::{vec, vec::Vec};
 
-use crate::battle::traits::{FightLog, IterationInput, IterationOutput, Selection, SimplePVE};
+use crate::battle::traits::{
+    BattleObserver, BattleState, FightLog, IterationInput, IterationOutput, Selection, SimplePVE,
+};
 use crate::contexts::{EnemyContext, SystemContext, WarriorContext, WarriorDeckContext};
 use crate::errors::Error;
+use crate::game::SporeRng;
 use crate::systems::{SystemController, SystemInput};
-use crate::wrappings::{Enemy, ItemClass, RequireTarget};
+use crate::wrappings::{ChallengeModifier, DifficultyRule, Enemy, RequireTarget, System};
 
 mod control;
 mod iteration;
 mod log;
 
+// round never exceeds the max round, so it's safe from wrapping around
+pub const DEFAULT_MAX_ROUND: u8 = 100;
+pub const DEFAULT_MAX_TURN_ACTIONS: u8 = 50;
+
 #[cfg(feature = "json_serde")]
 use serde::Serialize;
 
@@ -23,9 +31,28 @@ enum FightView {
     Card(usize),
 }
 
+// deferred battle procedures, which wait until all pending instructions are settled
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+enum Continuation {
+    EnemyAction(usize, Option<Vec<System>>),
+    RoundStart,
+}
+
+// battle state before a player action, the rng is saved to prevent rerolling by undo
+struct Checkpoint {
+    player: WarriorContext,
+    player_deck: WarriorDeckContext,
+    opponents: Vec<EnemyContext>,
+    last_output: IterationOutput,
+    turn_actions: u8,
+    rng: SporeRng,
+}
+
 #[cfg_attr(feature = "json_serde", derive(Serialize))]
 struct Instruction {
     target: Option<usize>,
+    // object which mounts the system, a triggered mounting system only resolves to it
+    owner: Option<usize>,
     ctx: SystemContext,
     view: FightView,
     system_input: Option<SystemInput>,
@@ -36,19 +63,146 @@ pub struct MapBattlePVE {
     player: WarriorContext,
     player_deck: WarriorDeckContext,
     opponents: Vec<EnemyContext>,
+    enemy_waves: VecDeque<Vec<Enemy>>,
+    wave: u8,
     round: u8,
     fight_logs: Vec<FightLog>,
     last_output: IterationOutput,
     pending_instructions: VecDeque<Instruction>,
+    continuations: VecDeque<Continuation>,
+    game_over: bool,
+    step_mode: bool,
+    max_round: u8,
+    max_turn_actions: u8,
+    turn_actions: u8,
+    difficulty_rules: Vec<DifficultyRule>,
+    modifiers: Vec<ChallengeModifier>,
+    #[cfg_attr(feature = "json_serde", serde(skip))]
+    checkpoints: Vec<Checkpoint>,
+    #[cfg_attr(feature = "json_serde", serde(skip))]
+    observers: Vec<Box<dyn BattleObserver>>,
+}
+
+impl MapBattlePVE {
+    pub fn add_observer(&mut self, observer: Box<dyn BattleObserver>) {
+        self.observers.push(observer);
+    }
+
+    // battle ends in timeout once the max round is finished, and player actions
+    // beyond the max turn actions are rejected
+    pub fn set_limits(&mut self, max_round: u8, max_turn_actions: u8) -> Result<(), Error> {
+        if max_round == 0 || max_turn_actions == 0 {
+            return Err(Error::BattleInvalidLimits);
+        }
+        self.max_round = max_round;
+        self.max_turn_actions = max_turn_actions;
+        Ok(())
+    }
+
+    // enemies summoned in battle are scaled by the same rules and modifiers as the map enemies
+    pub fn set_enemy_scaling(
+        &mut self,
+        difficulty_rules: Vec<DifficultyRule>,
+        modifiers: Vec<ChallengeModifier>,
+    ) {
+        self.difficulty_rules = difficulty_rules;
+        self.modifiers = modifiers;
+    }
+
+    // in step mode, operations only queue their instructions, which are run by `step`
+    pub fn set_step_mode(&mut self, step_mode: bool) {
+        self.step_mode = step_mode;
+    }
+
+    pub fn step(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<(IterationOutput, Vec<FightLog>), Error> {
+        if self.round == 0 {
+            return Err(Error::BattleNotStarted);
+        }
+        if let IterationOutput::RequireCardSelect(_, _) = self.last_output {
+            return Err(Error::BattleUnexpectedLastOutput);
+        }
+        if !self.operate_next_step(controller)? {
+            return Err(Error::BattleInstructionEmpty);
+        }
+        let logs = self.fight_logs.drain(..).collect();
+        Ok((self.last_output, logs))
+    }
+
+    // revert the last player action in this turn, the returned snapshot is the reverted state
+    pub fn undo(&mut self, controller: &mut SystemController) -> Result<FightLog, Error> {
+        if !self.is_settled() {
+            return Err(Error::BattleInstructionNotEmpty);
+        }
+        let Some(checkpoint) = self.checkpoints.pop() else {
+            return Err(Error::BattleUndoUnavailable);
+        };
+        self.restore(checkpoint, controller);
+        Ok(FightLog::Snapshot(
+            self.player.clone(),
+            self.opponents.clone(),
+        ))
+    }
+
+    fn restore(&mut self, checkpoint: Checkpoint, controller: &mut SystemController) {
+        self.player = checkpoint.player;
+        self.player_deck = checkpoint.player_deck;
+        self.opponents = checkpoint.opponents;
+        self.last_output = checkpoint.last_output;
+        self.turn_actions = checkpoint.turn_actions;
+        controller.rng = checkpoint.rng;
+    }
+
+    // the current wave number, starting from 1
+    pub fn wave(&self) -> u8 {
+        self.wave
+    }
+
+    pub fn remaining_waves(&self) -> usize {
+        self.enemy_waves.len()
+    }
+
+    pub fn undo_count(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.pending_instructions.is_empty() && self.continuations.is_empty()
+    }
+
+    pub fn current_instruction(&self) -> Option<&SystemContext> {
+        self.pending_instructions.front().map(|v| &v.ctx)
+    }
+
+    pub fn pending_instructions(&self) -> Vec<&SystemContext> {
+        self.pending_instructions.iter().map(|v| &v.ctx).collect()
+    }
+
+    fn battle_state(&self) -> BattleState {
+        BattleState {
+            round: self.round,
+            player: self.player.clone(),
+            player_deck: self.player_deck.clone(),
+            opponents: self.opponents.clone(),
+        }
+    }
 }
 
 impl SimplePVE for MapBattlePVE {
     fn create(
         player: WarriorContext,
         player_deck: WarriorDeckContext,
-        enemies: Vec<Enemy>,
+        enemy_waves: Vec<Vec<Enemy>>,
     ) -> Result<Self, Error> {
-        let opponents = enemies
+        if enemy_waves.is_empty() || enemy_waves.iter().any(|v| v.is_empty()) {
+            return Err(Error::BattleEnemyWaveEmpty);
+        }
+        let mut enemy_waves = VecDeque::from(enemy_waves);
+        let opponents = enemy_waves
+            .pop_front()
+            .unwrap()
             .into_iter()
             .enumerate()
             .map(|(i, enemy)| EnemyContext::new(enemy, i + 1))
@@ -57,10 +211,22 @@ impl SimplePVE for MapBattlePVE {
             player,
             player_deck,
             opponents,
+            enemy_waves,
+            wave: 1,
             round: 0,
             fight_logs: vec![],
             last_output: IterationOutput::Continue,
             pending_instructions: VecDeque::new(),
+            continuations: VecDeque::new(),
+            game_over: false,
+            step_mode: false,
+            max_round: DEFAULT_MAX_ROUND,
+            max_turn_actions: DEFAULT_MAX_TURN_ACTIONS,
+            turn_actions: 0,
+            difficulty_rules: vec![],
+            modifiers: vec![],
+            checkpoints: vec![],
+            observers: vec![],
         })
     }
 
@@ -71,14 +237,15 @@ impl SimplePVE for MapBattlePVE {
         if self.round != 0 {
             return Err(Error::BattleRepeatStart);
         }
-        let mut equipment_effects = vec![];
-        self.player.warrior.package_status.iter().for_each(|v| {
-            if v.class == ItemClass::Equipment {
-                v.system_pool
-                    .iter()
-                    .for_each(|effect| equipment_effects.push(effect.clone()));
-            }
-        });
+        // battle always settles its initial effects, regardless of the step mode
+        let step_mode = self.step_mode;
+        self.step_mode = false;
+        let equipment_effects = self
+            .player
+            .equipped
+            .iter()
+            .flat_map(|v| v.system_pool.clone())
+            .collect();
         self.trigger_log(FightLog::GameStart)?;
         self.trigger_log(FightLog::Snapshot(
             self.player.clone(),
@@ -93,9 +260,13 @@ impl SimplePVE for MapBattlePVE {
             .collect::<Result<Vec<_>, _>>()?;
         self.round = 1;
         self.trigger_log(FightLog::PlayerTurn(self.round))?;
-        self.player_draw(self.player.draw_count, controller)?;
+        let innate_count = self.player_draw_innate()?;
+        if innate_count < self.player.draw_count {
+            self.player_draw(self.player.draw_count - innate_count, controller)?;
+        }
         let output =
             self.trigger_iteration_systems(FightView::Player, equipment_effects, None, controller)?;
+        self.step_mode = step_mode;
         let logs = self.fight_logs.drain(..).collect();
         Ok((output, logs))
     }
@@ -110,7 +281,10 @@ impl SimplePVE for MapBattlePVE {
         }
         for operation in operations {
             let output = self.iterate(operation, controller)?;
-            if output == IterationOutput::GameWin || output == IterationOutput::GameLose {
+            if let IterationOutput::GameWin
+            | IterationOutput::GameLose
+            | IterationOutput::GameTimeout = output
+            {
                 let logs = self.fight_logs.drain(..).collect();
                 return Ok((self.last_output, logs));
             }
@@ -0,0 +1,366 @@
+extern crate alloc;
+use alloc::{vec, vec::Vec};
+use rand::RngCore;
+
+use crate::battle::pvp::{policy, FightView, Instruction, MapBattlePVP, Side};
+use crate::battle::traits::{FightLog, IterationOutput};
+use crate::contexts::{CtxAdaptor, SystemContext};
+use crate::errors::Error;
+use crate::systems::{Command, SystemController, SystemInput, SystemObjects, SystemReturn};
+use crate::wrappings::{RequireTarget, System, CARD_KEYWORD_INNATE};
+
+impl MapBattlePVP {
+    pub(super) fn draw(
+        &mut self,
+        side: Side,
+        draw_count: u8,
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        if draw_count == 0 {
+            return Err(Error::BattleUnexpectedDrawCount);
+        }
+        for _ in 0..draw_count {
+            let deck = self.deck_mut(side);
+            if deck.deck.is_empty() {
+                let mut grave_cards = deck.grave_deck.drain(..).collect::<Vec<_>>();
+                if grave_cards.is_empty() {
+                    return Err(Error::BattlePlayerDeckBroken);
+                }
+                deck.deck.append(&mut grave_cards);
+                self.trigger_log(FightLog::RecoverGraveDeck)?;
+            }
+            let deck = self.deck_mut(side);
+            let card_index = controller.rng.next_u32() as usize % deck.deck.len();
+            let card = deck.deck.remove(card_index);
+            let offset = card.offset();
+            deck.hand_deck.push(card);
+            self.trigger_log(FightLog::Draw(offset))?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn draw_innate(&mut self, side: Side) -> Result<u8, Error> {
+        let mut count = 0;
+        while let Some(card_index) = self
+            .deck(side)
+            .deck
+            .iter()
+            .position(|v| v.card.has_keyword(CARD_KEYWORD_INNATE))
+        {
+            let deck = self.deck_mut(side);
+            let card = deck.deck.remove(card_index);
+            let offset = card.offset();
+            deck.hand_deck.push(card);
+            self.trigger_log(FightLog::Draw(offset))?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    pub(super) fn select_draw(
+        &mut self,
+        side: Side,
+        card_offsets: Vec<usize>,
+    ) -> Result<(), Error> {
+        for offset in card_offsets {
+            let deck = self.deck_mut(side);
+            if !deck.selection_pool.contains(&offset) {
+                return Err(Error::BattleUnexpectedCardOffset);
+            }
+            let card = if let Some(index) = deck.deck.iter().position(|v| v.offset() == offset) {
+                deck.deck.remove(index)
+            } else if let Some(index) = deck.grave_deck.iter().position(|v| v.offset() == offset) {
+                deck.grave_deck.remove(index)
+            } else if let Some(card) = deck.unbelonging_deck.iter().find(|v| v.offset() == offset) {
+                card.clone()
+            } else {
+                return Err(Error::BattleDrawCardOffsetNotFound);
+            };
+            deck.hand_deck.push(card);
+            self.trigger_log(FightLog::Draw(offset))?;
+        }
+        self.deck_mut(side).selection_pool.clear();
+        Ok(())
+    }
+
+    pub(super) fn select_discard(
+        &mut self,
+        side: Side,
+        discard_offsets: Vec<usize>,
+        grave: bool,
+    ) -> Result<(), Error> {
+        if discard_offsets.is_empty() {
+            return Err(Error::BattleUnexpectedDiscardCount);
+        }
+        for offset in discard_offsets {
+            let deck = self.deck_mut(side);
+            let Some(index) = deck.hand_deck.iter().position(|v| v.offset() == offset) else {
+                break;
+            };
+            let hand_card = deck.hand_deck.remove(index);
+            if grave {
+                deck.grave_deck.push(hand_card);
+            } else {
+                deck.unavaliable_deck.push(hand_card);
+            }
+            self.trigger_log(FightLog::DiscardHandDeck(offset))?;
+        }
+        Ok(())
+    }
+
+    fn random_discard(
+        &mut self,
+        side: Side,
+        discard_count: u8,
+        grave: bool,
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        if discard_count == 0 {
+            return Err(Error::BattleUnexpectedDiscardCount);
+        }
+        for _ in 0..discard_count {
+            let deck = self.deck_mut(side);
+            if deck.hand_deck.is_empty() {
+                break;
+            }
+            let card_index = controller.rng.next_u32() as usize % deck.hand_deck.len();
+            let hand_card = deck.hand_deck.remove(card_index);
+            let offset = hand_card.offset();
+            if grave {
+                deck.grave_deck.push(hand_card);
+            } else {
+                deck.unavaliable_deck.push(hand_card);
+            }
+            self.trigger_log(FightLog::DiscardHandDeck(offset))?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn push_systems(&mut self, view: FightView, systems: Vec<System>) {
+        systems.into_iter().for_each(|system| {
+            self.pending_instructions.push_back(Instruction {
+                ctx: system.into(),
+                view,
+                owner: None,
+                system_input: None,
+            })
+        });
+    }
+
+    pub(super) fn operate_pending_instructions(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        if !self.game_over {
+            self.last_output = IterationOutput::Continue;
+        }
+        while let Some(instruction) = self.pending_instructions.pop_front() {
+            self.operate_instruction(instruction, controller)?;
+            if let IterationOutput::RequireCardSelect(_, _) = self.last_output {
+                break;
+            }
+        }
+        Ok(self.last_output)
+    }
+
+    fn collect_system_caster_offset(&self, view: FightView) -> usize {
+        match view {
+            FightView::Card(_, offset) => offset,
+            FightView::Warrior(side) => self.warrior(side).offset(),
+        }
+    }
+
+    // every target is resolved by the caster's side, since each side has only one warrior
+    fn collect_system_target_offsets(&self, view: FightView, target: RequireTarget) -> Vec<usize> {
+        let side = view.side();
+        match (view, target) {
+            (FightView::Card(_, offset), _) => vec![offset],
+            (_, RequireTarget::AllCharactors) => {
+                vec![self.player.offset(), self.opponent.offset()]
+            }
+            (_, RequireTarget::Opponent)
+            | (_, RequireTarget::RandomOpponent)
+            | (_, RequireTarget::AllOpponents) => vec![self.warrior(side.rival()).offset()],
+            (_, RequireTarget::Owner)
+            | (_, RequireTarget::RandomAlly)
+            | (_, RequireTarget::AllAllies)
+            | (_, RequireTarget::LowestHpAlly) => vec![self.warrior(side).offset()],
+        }
+    }
+
+    fn collect_system_objects(
+        &mut self,
+        view: FightView,
+        caster: usize,
+        source: Option<usize>,
+        targets: &[usize],
+    ) -> Result<SystemObjects, Error> {
+        let mut objects: Vec<&mut dyn CtxAdaptor> = vec![&mut self.player, &mut self.opponent];
+        if let FightView::Card(side, offset) = view {
+            let deck = match side {
+                Side::Player => &mut self.player_deck,
+                Side::Opponent => &mut self.opponent_deck,
+            };
+            let card = deck
+                .refer_card(offset)
+                .ok_or(Error::BattleInvalidCardOffsetToRefer)?;
+            objects.push(card);
+        }
+        SystemObjects::with_source(caster, source, targets, objects)
+    }
+
+    fn operate_instruction(
+        &mut self,
+        instruction: Instruction,
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        let Instruction {
+            ctx,
+            view,
+            owner,
+            mut system_input,
+        } = instruction;
+        let caster = owner.unwrap_or_else(|| self.collect_system_caster_offset(view));
+        self.trigger_log(FightLog::CallSystem(caster, ctx.clone()))?;
+        // a mounted system resolves to its owner, along with the targets it recorded
+        let targets = match owner {
+            Some(owner) => [owner].into_iter().chain(ctx.register_3.clone()).collect(),
+            None => self.collect_system_target_offsets(view, ctx.system.target_type),
+        };
+        if self.game_over {
+            system_input = Some(SystemInput::Trigger(FightLog::GameOver));
+        }
+        let source = match &system_input {
+            Some(SystemInput::Trigger(FightLog::SystemDamage(source, _, _))) => Some(*source),
+            _ => None,
+        };
+        let mut objects = self.collect_system_objects(view, caster, source, &targets)?;
+        let system_return = controller.system_call(ctx, &mut objects, system_input)?;
+        if self.game_over {
+            return Ok(());
+        }
+        if self.player.hp == 0 {
+            self.last_output = IterationOutput::GameLose;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(());
+        }
+        if self.opponent.hp == 0 {
+            self.last_output = IterationOutput::GameWin;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(());
+        }
+        self.operate_system_return(system_return, view, &targets, controller)
+    }
+
+    // deck operations go to the targeted warrior's deck, e.g. freezing the rival's hand cards
+    fn operate_system_return(
+        &mut self,
+        system_return: SystemReturn,
+        view: FightView,
+        targets: &[usize],
+        controller: &mut SystemController,
+    ) -> Result<(), Error> {
+        let side = view.side();
+        let deck_side = if targets.contains(&self.warrior(side.rival()).offset()) {
+            side.rival()
+        } else {
+            side
+        };
+        let mut return_cmds = vec![];
+        match system_return {
+            SystemReturn::Continue(cmds) => return_cmds = cmds,
+            SystemReturn::RequireCardSelect(select_count, draw_or_discard, operator) => {
+                if let Some(mut changer) = operator {
+                    let logs = changer(self.deck_mut(deck_side));
+                    return_cmds = vec![Command::AddLogs(logs)];
+                }
+                match deck_side {
+                    Side::Player => {
+                        self.last_output =
+                            IterationOutput::RequireCardSelect(select_count, draw_or_discard);
+                    }
+                    Side::Opponent => {
+                        let offsets = policy::select_cards(
+                            &self.opponent_deck,
+                            select_count,
+                            draw_or_discard,
+                        );
+                        if draw_or_discard {
+                            self.select_draw(Side::Opponent, offsets)?;
+                        } else if !offsets.is_empty() {
+                            self.select_discard(Side::Opponent, offsets, true)?;
+                        }
+                    }
+                }
+            }
+            SystemReturn::RequireDeckChange(mut changer) => {
+                let logs = changer(self.deck_mut(deck_side));
+                return_cmds = vec![Command::AddLogs(logs)];
+            }
+        };
+        for cmd in return_cmds {
+            match cmd {
+                Command::AddLogs(logs) => {
+                    logs.into_iter()
+                        .map(|log| self.trigger_log(log))
+                        .collect::<Result<Vec<_>, _>>()?;
+                }
+                Command::DrawCards(count) => self.draw(deck_side, count, controller)?,
+                Command::DiscardHandCards(count, to_grave) => {
+                    self.random_discard(deck_side, count, to_grave, controller)?
+                }
+                // there is no enemy to summon against another player
+                Command::SummonEnemies(_, _, _) => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn trigger_log(&mut self, log: FightLog) -> Result<(), Error> {
+        for side in [Side::Player, Side::Opponent] {
+            self.trigger_mounting_systems(
+                FightView::Warrior(side),
+                self.warrior(side).mounting_systems.clone(),
+                self.warrior(side).offset(),
+                log.clone(),
+            );
+            self.deck(side)
+                .collect_mountings()
+                .into_iter()
+                .for_each(|(card_offset, contexts)| {
+                    self.trigger_mounting_systems(
+                        FightView::Card(side, card_offset),
+                        contexts,
+                        card_offset,
+                        log.clone(),
+                    )
+                });
+        }
+        self.fight_logs.push(log);
+        Ok(())
+    }
+
+    fn trigger_mounting_systems(
+        &mut self,
+        view: FightView,
+        contexts: Vec<SystemContext>,
+        owner: usize,
+        log: FightLog,
+    ) {
+        // system calls are only recorded, otherwise mounted systems would trigger each other
+        // endlessly by their own calls
+        if let FightLog::CallSystem(_, _) = log {
+            return;
+        }
+        contexts.into_iter().for_each(|ctx| {
+            self.pending_instructions.push_back(Instruction {
+                ctx,
+                view,
+                owner: Some(owner),
+                system_input: Some(SystemInput::Trigger(log.clone())),
+            })
+        });
+    }
+}
@@ -0,0 +1,198 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::battle::pve::DEFAULT_MAX_TURN_ACTIONS;
+use crate::battle::pvp::policy::{self, Choice};
+use crate::battle::pvp::{FightView, MapBattlePVP, Side};
+use crate::battle::traits::{FightLog, IterationOutput};
+use crate::contexts::CtxAdaptor;
+use crate::errors::Error;
+use crate::systems::SystemController;
+use crate::wrappings::{CARD_KEYWORD_ETHEREAL, CARD_KEYWORD_EXHAUST, CARD_KEYWORD_RETAIN};
+
+impl MapBattlePVP {
+    pub(super) fn iterate_hand_card_use(
+        &mut self,
+        side: Side,
+        card_index: usize,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        if IterationOutput::Continue != self.last_output {
+            return Err(Error::BattleUnexpectedLastOutput);
+        }
+        let context = self
+            .deck(side)
+            .hand_deck
+            .get(card_index)
+            .ok_or(Error::BattleSelectionError)?;
+        if context.is_frozen() {
+            return Err(Error::BattleCardFrozen);
+        }
+        let cost = context.power_cost;
+        if self.warrior(side).power < cost {
+            return Err(Error::BattlePowerInsufficient);
+        }
+        self.warrior_mut(side).power -= cost;
+        let context = self.deck_mut(side).hand_deck.remove(card_index);
+        let systems = context.card.system_pool.clone();
+        self.trigger_log(FightLog::PowerCost(cost))?;
+        self.trigger_log(FightLog::HandCardUse(context.offset()))?;
+        if context.card.has_keyword(CARD_KEYWORD_EXHAUST) {
+            self.trigger_log(FightLog::ExhaustCard(context.offset()))?;
+            self.deck_mut(side).unavaliable_deck.push(context);
+        } else {
+            self.deck_mut(side).grave_deck.push(context);
+        }
+        self.push_systems(FightView::Warrior(side), systems);
+        self.operate_pending_instructions(controller)
+    }
+
+    pub(super) fn iterate_special_card_use(
+        &mut self,
+        side: Side,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        if IterationOutput::Continue != self.last_output {
+            return Err(Error::BattleUnexpectedLastOutput);
+        }
+        let deck = self.deck(side);
+        if deck.special_use_count >= deck.special_max_count {
+            return Err(Error::BattleUseCountInsufficient);
+        }
+        if self.warrior(side).is_silenced() {
+            return Err(Error::BattleSpecialCardSilenced);
+        }
+        let cost = deck.special_card.power_cost;
+        if self.warrior(side).power < cost {
+            return Err(Error::BattlePowerInsufficient);
+        }
+        self.warrior_mut(side).power -= cost;
+        self.deck_mut(side).special_use_count += 1;
+        self.trigger_log(FightLog::PowerCost(cost))?;
+        self.trigger_log(FightLog::SpecialCardUse)?;
+        let systems = self.deck(side).special_card.card.system_pool.clone();
+        self.push_systems(FightView::Warrior(side), systems);
+        self.operate_pending_instructions(controller)
+    }
+
+    pub(super) fn iterate_item_use(
+        &mut self,
+        item_index: usize,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        if item_index >= self.player.props_list.len() {
+            return Err(Error::BattleSelectionError);
+        }
+        if IterationOutput::Continue != self.last_output {
+            return Err(Error::BattleUnexpectedLastOutput);
+        }
+        let props_item = self.player.props_list.remove(item_index);
+        self.trigger_log(FightLog::ItemUse(item_index))?;
+        self.push_systems(FightView::Warrior(Side::Player), props_item.system_pool);
+        self.operate_pending_instructions(controller)
+    }
+
+    pub(super) fn iterate_pending_card_select(
+        &mut self,
+        card_offsets: Vec<usize>,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        let IterationOutput::RequireCardSelect(count, draw) = self.last_output else {
+            return Err(Error::BattleUnexpectedLastOutput);
+        };
+        if card_offsets.len() > count as usize {
+            return Err(Error::BattleExceedCardSelection);
+        }
+        if draw {
+            self.select_draw(Side::Player, card_offsets)?;
+        } else {
+            self.select_discard(Side::Player, card_offsets, true)?;
+        }
+        self.operate_pending_instructions(controller)
+    }
+
+    // opponent takes its whole turn at once, then the next round starts
+    pub(super) fn iterate_opponent_turn(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        if !self.pending_instructions.is_empty() {
+            return Err(Error::BattleInstructionNotEmpty);
+        }
+        if IterationOutput::Continue != self.last_output {
+            return Err(Error::BattleUnexpectedLastOutput);
+        }
+        self.turn_end(Side::Player, controller)?;
+        if !self.game_over {
+            self.turn_start(Side::Opponent, controller)?;
+        }
+        let mut actions = 0;
+        while !self.game_over && actions < DEFAULT_MAX_TURN_ACTIONS {
+            let Some(choice) = policy::choose(&self.opponent, &self.opponent_deck) else {
+                break;
+            };
+            match choice {
+                Choice::SpecialCard => self.iterate_special_card_use(Side::Opponent, controller)?,
+                Choice::HandCard(index) => {
+                    self.iterate_hand_card_use(Side::Opponent, index, controller)?
+                }
+            };
+            actions += 1;
+        }
+        if !self.game_over {
+            self.turn_end(Side::Opponent, controller)?;
+        }
+        if self.game_over {
+            return Ok(self.last_output);
+        }
+        if self.round >= self.max_round {
+            self.last_output = IterationOutput::GameTimeout;
+            self.trigger_log(FightLog::GameOver)?;
+            self.game_over = true;
+            return Ok(self.last_output);
+        }
+        self.round += 1;
+        self.turn_start(Side::Player, controller)
+    }
+
+    pub(super) fn turn_start(
+        &mut self,
+        side: Side,
+        controller: &mut SystemController,
+    ) -> Result<IterationOutput, Error> {
+        self.warrior_mut(side).round_reset();
+        self.deck_mut(side).round_reset();
+        match side {
+            Side::Player => self.trigger_log(FightLog::PlayerTurn(self.round))?,
+            Side::Opponent => self.trigger_log(FightLog::EnemyTurn(self.round))?,
+        }
+        let mut draw_count = self.warrior(side).draw_count;
+        if self.round == 1 {
+            draw_count = draw_count.saturating_sub(self.draw_innate(side)?);
+        }
+        if draw_count > 0 {
+            self.draw(side, draw_count, controller)?;
+        }
+        self.operate_pending_instructions(controller)
+    }
+
+    fn turn_end(&mut self, side: Side, controller: &mut SystemController) -> Result<(), Error> {
+        let (retained_hand_cards, remained_hand_cards): (Vec<_>, Vec<_>) = self
+            .deck_mut(side)
+            .hand_deck
+            .drain(..)
+            .partition(|v| v.card.has_keyword(CARD_KEYWORD_RETAIN));
+        self.deck_mut(side).hand_deck = retained_hand_cards;
+        for context in remained_hand_cards {
+            if context.card.has_keyword(CARD_KEYWORD_ETHEREAL) {
+                self.trigger_log(FightLog::ExhaustCard(context.offset()))?;
+                self.deck_mut(side).unavaliable_deck.push(context);
+            } else {
+                self.deck_mut(side).grave_deck.push(context);
+            }
+        }
+        self.trigger_log(FightLog::DiscardAllHandDeck)?;
+        self.operate_pending_instructions(controller)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+extern crate alloc;
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+use core::cmp::max;
+
+use crate::battle::pve::DEFAULT_MAX_ROUND;
+use crate::battle::traits::{FightLog, IterationInput, IterationOutput, Selection, SimplePVP};
+use crate::contexts::{CardContext, CtxAdaptor, SystemContext, WarriorContext, WarriorDeckContext};
+use crate::errors::Error;
+use crate::systems::{SystemController, SystemInput};
+use crate::wrappings::CARD_OFFSET_START;
+
+mod control;
+mod iteration;
+mod policy;
+
+pub const OPPONENT_OFFSET: usize = 1;
+
+#[cfg(feature = "json_serde")]
+use serde::Serialize;
+
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Player,
+    Opponent,
+}
+
+impl Side {
+    fn rival(self) -> Self {
+        match self {
+            Side::Player => Side::Opponent,
+            Side::Opponent => Side::Player,
+        }
+    }
+}
+
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(Clone, Copy, PartialEq)]
+enum FightView {
+    Warrior(Side),
+    Card(Side, usize),
+}
+
+impl FightView {
+    fn side(&self) -> Side {
+        match self {
+            FightView::Warrior(side) | FightView::Card(side, _) => *side,
+        }
+    }
+}
+
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+struct Instruction {
+    ctx: SystemContext,
+    view: FightView,
+    // object which mounts the system, a triggered mounting system only resolves to it
+    owner: Option<usize>,
+    system_input: Option<SystemInput>,
+}
+
+// decode another player's contexts, which are saved in rlp by the game session
+pub fn decode_opponent(
+    raw_context: &[u8],
+    raw_deck: &[u8],
+) -> Result<(WarriorContext, WarriorDeckContext), Error> {
+    let context = rlp::decode(raw_context).map_err(|_| Error::DeserializeError)?;
+    let deck = rlp::decode(raw_deck).map_err(|_| Error::DeserializeError)?;
+    Ok((context, deck))
+}
+
+fn deck_cards(deck: &mut WarriorDeckContext) -> impl Iterator<Item = &mut CardContext> {
+    core::iter::once(&mut deck.special_card)
+        .chain(deck.hand_deck.iter_mut())
+        .chain(deck.deck.iter_mut())
+        .chain(deck.grave_deck.iter_mut())
+        .chain(deck.unavaliable_deck.iter_mut())
+        .chain(deck.unbelonging_deck.iter_mut())
+}
+
+// the opponent is played by a deterministic policy, so the battle is replayable from
+// both players' contexts and the player's inputs only
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+pub struct MapBattlePVP {
+    player: WarriorContext,
+    player_deck: WarriorDeckContext,
+    opponent: WarriorContext,
+    opponent_deck: WarriorDeckContext,
+    round: u8,
+    max_round: u8,
+    fight_logs: Vec<FightLog>,
+    last_output: IterationOutput,
+    pending_instructions: VecDeque<Instruction>,
+    game_over: bool,
+}
+
+impl MapBattlePVP {
+    pub fn set_max_round(&mut self, max_round: u8) -> Result<(), Error> {
+        if max_round == 0 {
+            return Err(Error::BattleInvalidLimits);
+        }
+        self.max_round = max_round;
+        Ok(())
+    }
+
+    pub fn opponent(&self) -> &WarriorContext {
+        &self.opponent
+    }
+
+    pub fn opponent_deck(&self) -> &WarriorDeckContext {
+        &self.opponent_deck
+    }
+
+    fn warrior(&self, side: Side) -> &WarriorContext {
+        match side {
+            Side::Player => &self.player,
+            Side::Opponent => &self.opponent,
+        }
+    }
+
+    fn warrior_mut(&mut self, side: Side) -> &mut WarriorContext {
+        match side {
+            Side::Player => &mut self.player,
+            Side::Opponent => &mut self.opponent,
+        }
+    }
+
+    fn deck(&self, side: Side) -> &WarriorDeckContext {
+        match side {
+            Side::Player => &self.player_deck,
+            Side::Opponent => &self.opponent_deck,
+        }
+    }
+
+    fn deck_mut(&mut self, side: Side) -> &mut WarriorDeckContext {
+        match side {
+            Side::Player => &mut self.player_deck,
+            Side::Opponent => &mut self.opponent_deck,
+        }
+    }
+}
+
+impl SimplePVP for MapBattlePVP {
+    fn create(
+        player: WarriorContext,
+        mut player_deck: WarriorDeckContext,
+        mut opponent: WarriorContext,
+        mut opponent_deck: WarriorDeckContext,
+    ) -> Result<Self, Error> {
+        opponent.offset = OPPONENT_OFFSET;
+        opponent.reset();
+        opponent_deck.reset();
+        opponent_deck.special_use_count = 0;
+        // recorded card offsets may collide with the player's, so opponent cards are
+        // renumbered after the player's ones
+        let mut offset = deck_cards(&mut player_deck)
+            .map(|v| v.offset() + 1)
+            .fold(CARD_OFFSET_START, max);
+        deck_cards(&mut opponent_deck).for_each(|v| {
+            v.card.offset = offset;
+            offset += 1;
+        });
+        Ok(Self {
+            player,
+            player_deck,
+            opponent,
+            opponent_deck,
+            round: 0,
+            max_round: DEFAULT_MAX_ROUND,
+            fight_logs: vec![],
+            last_output: IterationOutput::Continue,
+            pending_instructions: VecDeque::new(),
+            game_over: false,
+        })
+    }
+
+    fn start(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<(IterationOutput, Vec<FightLog>), Error> {
+        if self.round != 0 {
+            return Err(Error::BattleRepeatStart);
+        }
+        self.trigger_log(FightLog::GameStart)?;
+        for side in [Side::Player, Side::Opponent] {
+            self.deck(side)
+                .collect_systems()
+                .into_iter()
+                .for_each(|(offset, effects)| {
+                    self.push_systems(FightView::Card(side, offset), effects)
+                });
+        }
+        self.operate_pending_instructions(controller)?;
+        self.round = 1;
+        for side in [Side::Player, Side::Opponent] {
+            let equipment_effects = self
+                .warrior(side)
+                .equipped
+                .iter()
+                .flat_map(|v| v.system_pool.clone())
+                .collect();
+            self.push_systems(FightView::Warrior(side), equipment_effects);
+        }
+        self.operate_pending_instructions(controller)?;
+        if !self.game_over {
+            self.turn_start(Side::Player, controller)?;
+        }
+        let logs = self.fight_logs.drain(..).collect();
+        Ok((self.last_output, logs))
+    }
+
+    fn run(
+        &mut self,
+        operations: Vec<IterationInput>,
+        controller: &mut SystemController,
+    ) -> Result<(IterationOutput, Vec<FightLog>), Error> {
+        if self.round == 0 {
+            return Err(Error::BattleNotStarted);
+        }
+        for operation in operations {
+            if self.game_over {
+                break;
+            }
+            match operation {
+                IterationInput::HandCardUse(Selection::SingleCard(card_index), _) => {
+                    self.iterate_hand_card_use(Side::Player, card_index, controller)?
+                }
+                IterationInput::SpecialCardUse(_) => {
+                    self.iterate_special_card_use(Side::Player, controller)?
+                }
+                IterationInput::ItemUse(Selection::Item(item_index), _) => {
+                    self.iterate_item_use(item_index, controller)?
+                }
+                IterationInput::PendingCardSelect(Selection::MultiCards(card_offsets)) => {
+                    self.iterate_pending_card_select(card_offsets, controller)?
+                }
+                IterationInput::EnemyTurn => self.iterate_opponent_turn(controller)?,
+                _ => return Err(Error::BattleInvalidIterationOperation),
+            };
+        }
+        let logs = self.fight_logs.drain(..).collect();
+        Ok((self.last_output, logs))
+    }
+
+    fn destroy(mut self) -> Result<(WarriorContext, WarriorDeckContext), Error> {
+        if !self.game_over {
+            return Err(Error::BattleNotFinished);
+        }
+        self.player.reset();
+        self.player_deck.reset();
+        Ok((self.player, self.player_deck))
+    }
+}
@@ -0,0 +1,43 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::contexts::{CtxAdaptor, WarriorContext, WarriorDeckContext};
+
+pub(super) enum Choice {
+    SpecialCard,
+    HandCard(usize),
+}
+
+// special card goes first, then the most expensive affordable hand card, ties are broken
+// by the hand order, so the same state always leads to the same choice
+pub(super) fn choose(warrior: &WarriorContext, deck: &WarriorDeckContext) -> Option<Choice> {
+    if deck.special_use_count < deck.special_max_count
+        && !warrior.is_silenced()
+        && deck.special_card.power_cost <= warrior.power
+    {
+        return Some(Choice::SpecialCard);
+    }
+    deck.hand_deck
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !v.is_frozen() && v.power_cost <= warrior.power)
+        .max_by(|(i, a), (j, b)| a.power_cost.cmp(&b.power_cost).then(j.cmp(i)))
+        .map(|(index, _)| Choice::HandCard(index))
+}
+
+// pick the leading candidates, which are the selection pool to draw or the hand to discard
+pub(super) fn select_cards(deck: &WarriorDeckContext, count: u8, draw: bool) -> Vec<usize> {
+    if draw {
+        deck.selection_pool
+            .iter()
+            .take(count as usize)
+            .cloned()
+            .collect()
+    } else {
+        deck.hand_deck
+            .iter()
+            .take(count as usize)
+            .map(|v| v.offset())
+            .collect()
+    }
+}
@@ -0,0 +1,28 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::battle::traits::{FightLog, IterationOutput};
+use crate::errors::Error;
+
+// bump it whenever the encoding of `FightLog` or `IterationOutput` changes
+pub const FIGHT_STREAM_VERSION: u8 = 2;
+
+pub fn encode_fight_stream(output: &IterationOutput, logs: &[FightLog]) -> Vec<u8> {
+    let mut stream = rlp::RlpStream::new_list(3);
+    stream
+        .append(&FIGHT_STREAM_VERSION)
+        .append(output)
+        .append_list(logs);
+    stream.out().to_vec()
+}
+
+pub fn decode_fight_stream(raw_stream: &[u8]) -> Result<(IterationOutput, Vec<FightLog>), Error> {
+    let rlp = rlp::Rlp::new(raw_stream);
+    let version: u8 = rlp.val_at(0).map_err(|_| Error::DeserializeError)?;
+    if version != FIGHT_STREAM_VERSION {
+        return Err(Error::BattleStreamVersionUnsupported);
+    }
+    let output = rlp.val_at(1).map_err(|_| Error::DeserializeError)?;
+    let logs = rlp.list_at(2).map_err(|_| Error::DeserializeError)?;
+    Ok((output, logs))
+}
@@ -44,6 +44,40 @@ pub enum IterationOutput {
     GameLose,
     RequireCardSelect(u8, bool),
     PlayerTurn,
+    GameTimeout,
+}
+
+impl rlp::Encodable for IterationOutput {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        match self {
+            IterationOutput::Continue => s.begin_list(1).append(&0u8),
+            IterationOutput::GameWin => s.begin_list(1).append(&1u8),
+            IterationOutput::GameLose => s.begin_list(1).append(&2u8),
+            IterationOutput::RequireCardSelect(count, draw) => {
+                s.begin_list(3).append(&3u8).append(count).append(draw)
+            }
+            IterationOutput::PlayerTurn => s.begin_list(1).append(&4u8),
+            IterationOutput::GameTimeout => s.begin_list(1).append(&5u8),
+        };
+    }
+}
+
+impl rlp::Decodable for IterationOutput {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let code: u8 = rlp.val_at(0)?;
+        match code {
+            0 => Ok(IterationOutput::Continue),
+            1 => Ok(IterationOutput::GameWin),
+            2 => Ok(IterationOutput::GameLose),
+            3 => Ok(IterationOutput::RequireCardSelect(
+                rlp.val_at(1)?,
+                rlp.val_at(2)?,
+            )),
+            4 => Ok(IterationOutput::PlayerTurn),
+            5 => Ok(IterationOutput::GameTimeout),
+            _ => Err(rlp::DecoderError::Custom("invalid IterationOutput")),
+        }
+    }
 }
 
 #[cfg_attr(feature = "json_serde", derive(Serialize))]
@@ -72,7 +106,7 @@ pub enum FightLog {
     UpdateSystem(usize, SystemContext),
     RemoveSystem(usize, SystemContext),
 
-    SystemDamage(usize, u16),
+    SystemDamage(usize, usize, u16),
     SystemArmorUp(usize, u16),
     SystemArmorDown(usize, u16),
     SystemShieldUp(usize, u16),
@@ -91,52 +125,257 @@ pub enum FightLog {
     SystemMaxHpUp(u16),
     SystemMaxHpDown(u16),
     SystemPowerCostChange(usize, u8),
+    SystemPoisonDamage(usize, u16),
+    SystemBurnDamage(usize, u16),
+    SystemBleedDamage(usize, u16),
+    SystemThornsDamage(usize, u16),
+    SystemReflectDamage(usize, u16),
+    EnemyStunned(usize),
+    ExhaustCard(usize),
+    UpgradeCard(usize),
+    RemoveCard(usize),
+    TransformCard(usize),
+    EnemyPhaseChange(usize, u8),
+    SummonEnemy(usize),
+    EnemyWave(u8),
+}
+
+impl From<&FightLog> for u16 {
+    fn from(value: &FightLog) -> Self {
+        match value {
+            FightLog::GameStart => 0,
+            FightLog::Snapshot(_, _) => 1,
+            FightLog::EnemyTurn(_) => 2,
+            FightLog::PlayerTurn(_) => 3,
+            FightLog::GameOver => 4,
+            FightLog::PowerCost(_) => 5,
+            FightLog::SpecialCardUse => 6,
+            FightLog::HandCardUse(_) => 7,
+            FightLog::ItemUse(_) => 8,
+            FightLog::Draw(_) => 9,
+            FightLog::DiscardAllHandDeck => 10,
+            FightLog::DiscardHandDeck(_) => 11,
+            FightLog::RecoverGraveDeck => 12,
+            FightLog::RecoverPower => 13,
+            FightLog::RecoverCardCost => 14,
+            FightLog::RecoverHp(_) => 15,
+            FightLog::CallSystem(_, _) => 16,
+            FightLog::AddSystem(_, _) => 17,
+            FightLog::UpdateSystem(_, _) => 18,
+            FightLog::RemoveSystem(_, _) => 19,
+            FightLog::SystemDamage(_, _, _) => 20,
+            FightLog::SystemArmorUp(_, _) => 21,
+            FightLog::SystemArmorDown(_, _) => 22,
+            FightLog::SystemShieldUp(_, _) => 23,
+            FightLog::SystemShieldDown(_, _) => 24,
+            FightLog::SystemRecoverHp(_, _) => 25,
+            FightLog::SystemDrawCountUp(_) => 26,
+            FightLog::SystemDrawCountDown(_) => 27,
+            FightLog::SystemAttackPowerUp(_, _) => 28,
+            FightLog::SystemAttackPowerDown(_, _) => 29,
+            FightLog::SystemDefensePowerUp(_, _) => 30,
+            FightLog::SystemDefensePowerDown(_, _) => 31,
+            FightLog::SystemAttackWeakUp(_, _) => 32,
+            FightLog::SystemAttackWeakDown(_, _) => 33,
+            FightLog::SystemDefenseWeakUp(_, _) => 34,
+            FightLog::SystemDefenseWeakDown(_, _) => 35,
+            FightLog::SystemMaxHpUp(_) => 36,
+            FightLog::SystemMaxHpDown(_) => 37,
+            FightLog::SystemPowerCostChange(_, _) => 38,
+            FightLog::SystemPoisonDamage(_, _) => 39,
+            FightLog::SystemBurnDamage(_, _) => 40,
+            FightLog::SystemBleedDamage(_, _) => 41,
+            FightLog::SystemThornsDamage(_, _) => 42,
+            FightLog::SystemReflectDamage(_, _) => 43,
+            FightLog::EnemyStunned(_) => 44,
+            FightLog::ExhaustCard(_) => 45,
+            FightLog::UpgradeCard(_) => 46,
+            FightLog::RemoveCard(_) => 47,
+            FightLog::TransformCard(_) => 48,
+            FightLog::EnemyPhaseChange(_, _) => 49,
+            FightLog::SummonEnemy(_) => 50,
+            FightLog::EnemyWave(_) => 51,
+        }
+    }
 }
 
 impl PartialEq<u16> for FightLog {
     fn eq(&self, other: &u16) -> bool {
-        *other
-            == match self {
-                FightLog::GameStart => 0,
-                FightLog::Snapshot(_, _) => 1,
-                FightLog::EnemyTurn(_) => 2,
-                FightLog::PlayerTurn(_) => 3,
-                FightLog::GameOver => 4,
-                FightLog::PowerCost(_) => 5,
-                FightLog::SpecialCardUse => 6,
-                FightLog::HandCardUse(_) => 7,
-                FightLog::ItemUse(_) => 8,
-                FightLog::Draw(_) => 9,
-                FightLog::DiscardAllHandDeck => 10,
-                FightLog::DiscardHandDeck(_) => 11,
-                FightLog::RecoverGraveDeck => 12,
-                FightLog::RecoverPower => 13,
-                FightLog::RecoverCardCost => 14,
-                FightLog::RecoverHp(_) => 15,
-                FightLog::CallSystem(_, _) => 16,
-                FightLog::AddSystem(_, _) => 17,
-                FightLog::UpdateSystem(_, _) => 18,
-                FightLog::RemoveSystem(_, _) => 19,
-                FightLog::SystemDamage(_, _) => 20,
-                FightLog::SystemArmorUp(_, _) => 21,
-                FightLog::SystemArmorDown(_, _) => 22,
-                FightLog::SystemShieldUp(_, _) => 23,
-                FightLog::SystemShieldDown(_, _) => 24,
-                FightLog::SystemRecoverHp(_, _) => 25,
-                FightLog::SystemDrawCountUp(_) => 26,
-                FightLog::SystemDrawCountDown(_) => 27,
-                FightLog::SystemAttackPowerUp(_, _) => 28,
-                FightLog::SystemAttackPowerDown(_, _) => 29,
-                FightLog::SystemDefensePowerUp(_, _) => 30,
-                FightLog::SystemDefensePowerDown(_, _) => 31,
-                FightLog::SystemAttackWeakUp(_, _) => 32,
-                FightLog::SystemAttackWeakDown(_, _) => 33,
-                FightLog::SystemDefenseWeakUp(_, _) => 34,
-                FightLog::SystemDefenseWeakDown(_, _) => 35,
-                FightLog::SystemMaxHpUp(_) => 36,
-                FightLog::SystemMaxHpDown(_) => 37,
-                FightLog::SystemPowerCostChange(_, _) => 38,
+        u16::from(self) == *other
+    }
+}
+
+impl rlp::Encodable for FightLog {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        let code = u16::from(self);
+        match self {
+            FightLog::GameStart
+            | FightLog::GameOver
+            | FightLog::SpecialCardUse
+            | FightLog::DiscardAllHandDeck
+            | FightLog::RecoverGraveDeck
+            | FightLog::RecoverPower
+            | FightLog::RecoverCardCost => {
+                s.begin_list(1).append(&code);
+            }
+            FightLog::Snapshot(player, enemies) => {
+                s.begin_list(3)
+                    .append(&code)
+                    .append(player)
+                    .append_list(enemies);
             }
+            FightLog::EnemyTurn(value)
+            | FightLog::PlayerTurn(value)
+            | FightLog::PowerCost(value)
+            | FightLog::SystemDrawCountUp(value)
+            | FightLog::SystemDrawCountDown(value)
+            | FightLog::EnemyWave(value) => {
+                s.begin_list(2).append(&code).append(value);
+            }
+            FightLog::RecoverHp(value)
+            | FightLog::SystemMaxHpUp(value)
+            | FightLog::SystemMaxHpDown(value) => {
+                s.begin_list(2).append(&code).append(value);
+            }
+            FightLog::HandCardUse(offset)
+            | FightLog::ItemUse(offset)
+            | FightLog::Draw(offset)
+            | FightLog::DiscardHandDeck(offset)
+            | FightLog::EnemyStunned(offset)
+            | FightLog::ExhaustCard(offset)
+            | FightLog::UpgradeCard(offset)
+            | FightLog::RemoveCard(offset)
+            | FightLog::TransformCard(offset)
+            | FightLog::SummonEnemy(offset) => {
+                s.begin_list(2).append(&code).append(offset);
+            }
+            FightLog::CallSystem(offset, ctx)
+            | FightLog::AddSystem(offset, ctx)
+            | FightLog::UpdateSystem(offset, ctx)
+            | FightLog::RemoveSystem(offset, ctx) => {
+                s.begin_list(3).append(&code).append(offset).append(ctx);
+            }
+            FightLog::SystemDamage(source, offset, value) => {
+                s.begin_list(4)
+                    .append(&code)
+                    .append(source)
+                    .append(offset)
+                    .append(value);
+            }
+            FightLog::SystemArmorUp(offset, value)
+            | FightLog::SystemArmorDown(offset, value)
+            | FightLog::SystemShieldUp(offset, value)
+            | FightLog::SystemShieldDown(offset, value)
+            | FightLog::SystemRecoverHp(offset, value)
+            | FightLog::SystemPoisonDamage(offset, value)
+            | FightLog::SystemBurnDamage(offset, value)
+            | FightLog::SystemBleedDamage(offset, value)
+            | FightLog::SystemThornsDamage(offset, value)
+            | FightLog::SystemReflectDamage(offset, value) => {
+                s.begin_list(3).append(&code).append(offset).append(value);
+            }
+            FightLog::SystemAttackPowerUp(offset, value)
+            | FightLog::SystemAttackPowerDown(offset, value)
+            | FightLog::SystemDefensePowerUp(offset, value)
+            | FightLog::SystemDefensePowerDown(offset, value)
+            | FightLog::SystemAttackWeakUp(offset, value)
+            | FightLog::SystemAttackWeakDown(offset, value)
+            | FightLog::SystemDefenseWeakUp(offset, value)
+            | FightLog::SystemDefenseWeakDown(offset, value)
+            | FightLog::SystemPowerCostChange(offset, value)
+            | FightLog::EnemyPhaseChange(offset, value) => {
+                s.begin_list(3).append(&code).append(offset).append(value);
+            }
+        }
+    }
+}
+
+impl rlp::Decodable for FightLog {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let code: u16 = rlp.val_at(0)?;
+        let log = match code {
+            0 => FightLog::GameStart,
+            1 => FightLog::Snapshot(rlp.val_at(1)?, rlp.list_at(2)?),
+            2 => FightLog::EnemyTurn(rlp.val_at(1)?),
+            3 => FightLog::PlayerTurn(rlp.val_at(1)?),
+            4 => FightLog::GameOver,
+            5 => FightLog::PowerCost(rlp.val_at(1)?),
+            6 => FightLog::SpecialCardUse,
+            7 => FightLog::HandCardUse(rlp.val_at(1)?),
+            8 => FightLog::ItemUse(rlp.val_at(1)?),
+            9 => FightLog::Draw(rlp.val_at(1)?),
+            10 => FightLog::DiscardAllHandDeck,
+            11 => FightLog::DiscardHandDeck(rlp.val_at(1)?),
+            12 => FightLog::RecoverGraveDeck,
+            13 => FightLog::RecoverPower,
+            14 => FightLog::RecoverCardCost,
+            15 => FightLog::RecoverHp(rlp.val_at(1)?),
+            16 => FightLog::CallSystem(rlp.val_at(1)?, rlp.val_at(2)?),
+            17 => FightLog::AddSystem(rlp.val_at(1)?, rlp.val_at(2)?),
+            18 => FightLog::UpdateSystem(rlp.val_at(1)?, rlp.val_at(2)?),
+            19 => FightLog::RemoveSystem(rlp.val_at(1)?, rlp.val_at(2)?),
+            20 => FightLog::SystemDamage(rlp.val_at(1)?, rlp.val_at(2)?, rlp.val_at(3)?),
+            21 => FightLog::SystemArmorUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            22 => FightLog::SystemArmorDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            23 => FightLog::SystemShieldUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            24 => FightLog::SystemShieldDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            25 => FightLog::SystemRecoverHp(rlp.val_at(1)?, rlp.val_at(2)?),
+            26 => FightLog::SystemDrawCountUp(rlp.val_at(1)?),
+            27 => FightLog::SystemDrawCountDown(rlp.val_at(1)?),
+            28 => FightLog::SystemAttackPowerUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            29 => FightLog::SystemAttackPowerDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            30 => FightLog::SystemDefensePowerUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            31 => FightLog::SystemDefensePowerDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            32 => FightLog::SystemAttackWeakUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            33 => FightLog::SystemAttackWeakDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            34 => FightLog::SystemDefenseWeakUp(rlp.val_at(1)?, rlp.val_at(2)?),
+            35 => FightLog::SystemDefenseWeakDown(rlp.val_at(1)?, rlp.val_at(2)?),
+            36 => FightLog::SystemMaxHpUp(rlp.val_at(1)?),
+            37 => FightLog::SystemMaxHpDown(rlp.val_at(1)?),
+            38 => FightLog::SystemPowerCostChange(rlp.val_at(1)?, rlp.val_at(2)?),
+            39 => FightLog::SystemPoisonDamage(rlp.val_at(1)?, rlp.val_at(2)?),
+            40 => FightLog::SystemBurnDamage(rlp.val_at(1)?, rlp.val_at(2)?),
+            41 => FightLog::SystemBleedDamage(rlp.val_at(1)?, rlp.val_at(2)?),
+            42 => FightLog::SystemThornsDamage(rlp.val_at(1)?, rlp.val_at(2)?),
+            43 => FightLog::SystemReflectDamage(rlp.val_at(1)?, rlp.val_at(2)?),
+            44 => FightLog::EnemyStunned(rlp.val_at(1)?),
+            45 => FightLog::ExhaustCard(rlp.val_at(1)?),
+            46 => FightLog::UpgradeCard(rlp.val_at(1)?),
+            47 => FightLog::RemoveCard(rlp.val_at(1)?),
+            48 => FightLog::TransformCard(rlp.val_at(1)?),
+            49 => FightLog::EnemyPhaseChange(rlp.val_at(1)?, rlp.val_at(2)?),
+            50 => FightLog::SummonEnemy(rlp.val_at(1)?),
+            51 => FightLog::EnemyWave(rlp.val_at(1)?),
+            _ => return Err(rlp::DecoderError::Custom("invalid FightLog")),
+        };
+        Ok(log)
+    }
+}
+
+// snapshot of the battle, which is only cloned out if any observer is attached
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct BattleState {
+    pub round: u8,
+    pub player: WarriorContext,
+    pub player_deck: WarriorDeckContext,
+    pub opponents: Vec<EnemyContext>,
+}
+
+pub trait BattleObserver {
+    // called on every triggered log, in the same order as the returned logs
+    fn on_log(&mut self, _log: &FightLog) {}
+
+    // called right before a system is executed
+    fn on_system_call(&mut self, _caster: usize, _ctx: &SystemContext, _targets: &[usize]) {}
+
+    // called right after a system is executed, with the battle states around it
+    fn on_state_change(
+        &mut self,
+        _ctx: &SystemContext,
+        _before: &BattleState,
+        _after: &BattleState,
+    ) {
     }
 }
 
@@ -147,7 +386,7 @@ where
     fn create(
         player: WarriorContext,
         player_deck: WarriorDeckContext,
-        enemies: Vec<Enemy>,
+        enemy_waves: Vec<Vec<Enemy>>,
     ) -> Result<Self, Error>;
 
     fn start(
@@ -165,3 +404,28 @@ where
 
     fn destroy(self) -> Result<(WarriorContext, WarriorDeckContext, Vec<EnemyContext>), Error>;
 }
+
+pub trait SimplePVP
+where
+    Self: Sized,
+{
+    fn create(
+        player: WarriorContext,
+        player_deck: WarriorDeckContext,
+        opponent: WarriorContext,
+        opponent_deck: WarriorDeckContext,
+    ) -> Result<Self, Error>;
+
+    fn start(
+        &mut self,
+        controller: &mut SystemController,
+    ) -> Result<(IterationOutput, Vec<FightLog>), Error>;
+
+    fn run(
+        &mut self,
+        operations: Vec<IterationInput>,
+        controller: &mut SystemController,
+    ) -> Result<(IterationOutput, Vec<FightLog>), Error>;
+
+    fn destroy(self) -> Result<(WarriorContext, WarriorDeckContext), Error>;
+}
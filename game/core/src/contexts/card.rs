@@ -7,7 +7,7 @@ use crate::contexts::{
     ContextType, CtxAdaptor, SystemContext,
 };
 use crate::errors::Error;
-use crate::wrappings::Card;
+use crate::wrappings::{Card, SystemId};
 
 #[cfg(feature = "json_serde")]
 use serde::{Deserialize, Serialize};
@@ -42,6 +42,26 @@ impl CardContext {
         self.power_cost = self.game_max_power_cost;
         self.mounting_systems.clear();
     }
+
+    // cost changes made upon the old card are kept upon the upgraded one
+    pub fn upgrade(&mut self, card: Card) {
+        let (old_cost, new_cost) = (self.card.power_cost, card.power_cost);
+        self.power_cost = rebase_cost(self.power_cost, old_cost, new_cost);
+        self.battle_max_power_cost = rebase_cost(self.battle_max_power_cost, old_cost, new_cost);
+        self.game_max_power_cost = rebase_cost(self.game_max_power_cost, old_cost, new_cost);
+        self.card = card;
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.mounting_systems
+            .iter()
+            .any(|v| v.system.system_id == SystemId::TriggerFreeze)
+    }
+}
+
+fn rebase_cost(cost: u8, old_base: u8, new_base: u8) -> u8 {
+    let cost = cost as i16 - old_base as i16 + new_base as i16;
+    cost.clamp(0, u8::MAX as i16) as u8
 }
 
 impl CtxAdaptor for CardContext {
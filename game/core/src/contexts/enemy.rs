@@ -4,14 +4,28 @@ use rand::RngCore;
 
 use crate::contexts::{
     add_mounting_system_internal, remove_mounting_system_internal, update_mounting_system_internal,
-    ContextType, CtxAdaptor, SystemContext,
+    ContextType, CtxAdaptor, SystemContext, WarriorContext,
 };
 use crate::errors::Error;
-use crate::wrappings::{Enemy, System};
+use crate::wrappings::{
+    randomized_weighted_selection, Action, ActionCondition, ActionStrategy, Enemy, System, SystemId,
+};
 
 #[cfg(feature = "json_serde")]
 use serde::Serialize;
 
+fn randomized_effects(action: &Action, rng: &mut impl RngCore) -> Vec<System> {
+    let mut randomized_effects = action.system_pool.clone();
+    if action.random_select {
+        let mut effects = randomized_effects.drain(..).collect::<Vec<_>>();
+        while !effects.is_empty() {
+            let offset = rng.next_u32() as usize % effects.len();
+            randomized_effects.push(effects.remove(offset));
+        }
+    }
+    randomized_effects
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize))]
 #[derive(Clone)]
@@ -26,6 +40,8 @@ pub struct EnemyContext {
     pub defense: u8,
     pub defense_weak: u8,
     pub strategy: Vec<Vec<System>>,
+    pub phase: u8,
+    pub cooldowns: Vec<u8>,
     pub mounting_systems: Vec<SystemContext>,
 }
 
@@ -41,12 +57,30 @@ impl EnemyContext {
             defense: enemy.defense,
             defense_weak: enemy.defense_weak,
             strategy: vec![],
+            phase: 0,
+            cooldowns: vec![],
             mounting_systems: vec![],
             enemy,
         }
     }
 
-    pub fn pop_action(&mut self, rng: &mut impl RngCore) -> Result<Vec<System>, Error> {
+    fn active_strategy(&self) -> &ActionStrategy {
+        match self.phase {
+            0 => &self.enemy.strategy,
+            phase => &self.enemy.phases[phase as usize - 1].strategy,
+        }
+    }
+
+    // conditional rules take precedence, the rotation is the fallback if no rule is available
+    pub fn pop_action(
+        &mut self,
+        player: &WarriorContext,
+        round: u8,
+        rng: &mut impl RngCore,
+    ) -> Result<Vec<System>, Error> {
+        if let Some(effects) = self.pop_rule_action(player, round, rng) {
+            return Ok(effects);
+        }
         if self.strategy.is_empty() {
             self.reset_strategy(rng);
         }
@@ -56,27 +90,133 @@ impl EnemyContext {
         Ok(self.strategy.remove(0))
     }
 
+    // phase 0 is the original strategy, the n-th phase is `enemy.phases[n - 1]`
+    pub fn update_phase(&mut self) -> Option<u8> {
+        let hp = self.hp as u32 * 100;
+        let max_hp = self.enemy.hp as u32;
+        let phase = self
+            .enemy
+            .phases
+            .iter()
+            .rposition(|v| hp <= max_hp * v.hp_percent as u32)
+            .map(|i| i as u8 + 1)?;
+        if phase <= self.phase {
+            return None;
+        }
+        self.phase = phase;
+        self.strategy.clear();
+        self.cooldowns.clear();
+        Some(phase)
+    }
+
+    fn pop_rule_action(
+        &mut self,
+        player: &WarriorContext,
+        round: u8,
+        rng: &mut impl RngCore,
+    ) -> Option<Vec<System>> {
+        let rules = match self.phase {
+            0 => &self.enemy.strategy.rules,
+            phase => &self.enemy.phases[phase as usize - 1].strategy.rules,
+        };
+        if rules.is_empty() {
+            return None;
+        }
+        let hp = self.hp as u32 * 100;
+        let max_hp = self.enemy.hp as u32;
+        self.cooldowns.resize(rules.len(), 0);
+        let available = rules
+            .iter()
+            .enumerate()
+            .filter(|(i, rule)| {
+                self.cooldowns[*i] == 0
+                    && rule.conditions.iter().all(|condition| match condition {
+                        ActionCondition::OwnHpBelow(percent) => hp < max_hp * *percent as u32,
+                        ActionCondition::PlayerShieldAbove(shield) => player.shield > *shield,
+                        ActionCondition::RoundMultipleOf(n) => round % n == 0,
+                    })
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let selected =
+            randomized_weighted_selection(available, 1, |i| rules[*i].weight as u32, rng).pop();
+        self.cooldowns
+            .iter_mut()
+            .for_each(|v| *v = v.saturating_sub(1));
+        let index = selected?;
+        self.cooldowns[index] = rules[index].cooldown;
+        Some(randomized_effects(&rules[index].action, rng))
+    }
+
     pub fn reset_strategy(&mut self, rng: &mut impl RngCore) {
-        let mut randomized_actions = self.enemy.strategy.actions.iter().collect::<Vec<_>>();
-        if self.enemy.strategy.random_select {
+        let strategy = self.active_strategy();
+        let mut randomized_actions = strategy.actions.iter().collect::<Vec<_>>();
+        if strategy.random_select {
             let mut actions = randomized_actions.drain(..).collect::<Vec<_>>();
             while !actions.is_empty() {
                 let offset = rng.next_u32() as usize % actions.len();
                 randomized_actions.push(actions.remove(offset));
             }
         }
-        self.strategy.clear();
-        randomized_actions.into_iter().for_each(|action| {
-            let mut randomized_effects = action.system_pool.clone().into_iter().collect::<Vec<_>>();
-            if action.random_select {
-                let mut effects = randomized_effects.drain(..).collect::<Vec<_>>();
-                while !effects.is_empty() {
-                    let offset = rng.next_u32() as usize % effects.len();
-                    randomized_effects.push(effects.remove(offset));
-                }
-            }
-            self.strategy.push(randomized_effects);
+        let strategy = randomized_actions
+            .into_iter()
+            .map(|action| randomized_effects(action, rng))
+            .collect();
+        self.strategy = strategy;
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.mounting_systems
+            .iter()
+            .any(|v| v.system.system_id == SystemId::TriggerStun)
+    }
+}
+
+// strategy is a nested list, which the rlp derive macro doesn't support
+impl rlp::Encodable for EnemyContext {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        s.begin_list(13)
+            .append(&self.enemy)
+            .append(&self.offset)
+            .append(&self.hp)
+            .append(&self.armor)
+            .append(&self.shield)
+            .append(&self.attack)
+            .append(&self.attack_weak)
+            .append(&self.defense)
+            .append(&self.defense_weak);
+        s.begin_list(self.strategy.len());
+        self.strategy.iter().for_each(|effects| {
+            s.append_list(effects);
         });
+        s.append(&self.phase)
+            .append_list(&self.cooldowns)
+            .append_list(&self.mounting_systems);
+    }
+}
+
+impl rlp::Decodable for EnemyContext {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let strategy = rlp
+            .at(9)?
+            .iter()
+            .map(|effects| effects.as_list())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            enemy: rlp.val_at(0)?,
+            offset: rlp.val_at(1)?,
+            hp: rlp.val_at(2)?,
+            armor: rlp.val_at(3)?,
+            shield: rlp.val_at(4)?,
+            attack: rlp.val_at(5)?,
+            attack_weak: rlp.val_at(6)?,
+            defense: rlp.val_at(7)?,
+            defense_weak: rlp.val_at(8)?,
+            strategy,
+            phase: rlp.val_at(10)?,
+            cooldowns: rlp.list_at(11)?,
+            mounting_systems: rlp.list_at(12)?,
+        })
     }
 }
 
@@ -51,13 +51,12 @@ fn add_mounting_system_internal(
     if !ctx.is_durable() {
         return false;
     }
-    let Some(exist_ctx) = mounting_systems.iter_mut().find(|v| v.equal(&ctx)) else {
-        return false;
-    };
-    if !exist_ctx.durable_combine(ctx) {
-        mounting_systems.push(ctx.clone());
-        return false;
+    if let Some(exist_ctx) = mounting_systems.iter_mut().find(|v| v.equal(&ctx)) {
+        if exist_ctx.durable_combine(ctx) {
+            return true;
+        }
     }
+    mounting_systems.push(ctx.clone());
     true
 }
 
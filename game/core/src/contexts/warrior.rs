@@ -8,7 +8,7 @@ use crate::contexts::{
     CardContext, ContextType, CtxAdaptor,
 };
 use crate::errors::Error;
-use crate::wrappings::{Card, Item, ItemClass, Potion, System, Warrior};
+use crate::wrappings::{Card, EquipmentSlot, Item, ItemClass, Potion, System, SystemId, Warrior};
 
 #[cfg(feature = "json_serde")]
 use serde::{Deserialize, Serialize};
@@ -26,6 +26,8 @@ pub struct WarriorDeckContext {
     pub unavaliable_deck: Vec<CardContext>,
     pub selection_pool: Vec<usize>,
     pub unbelonging_deck: Vec<CardContext>,
+    pub removal_count: u8,
+    pub transform_count: u8,
 }
 
 impl WarriorDeckContext {
@@ -45,6 +47,8 @@ impl WarriorDeckContext {
             unavaliable_deck: vec![],
             selection_pool: vec![],
             unbelonging_deck: vec![],
+            removal_count: 0,
+            transform_count: 0,
         }
     }
 
@@ -159,15 +163,24 @@ pub struct WarriorContext {
     pub draw_count: u8,
     pub physique: u8,
     pub equipment_list: Vec<Item>,
+    pub equipped: Vec<Item>,
     pub props_list: Vec<Item>,
     pub mounting_systems: Vec<SystemContext>,
 }
 
 impl WarriorContext {
-    pub fn new(warrior: Warrior, potion: Option<Potion>) -> (Self, WarriorDeckContext) {
+    pub fn new(
+        warrior: Warrior,
+        potion: Option<Potion>,
+    ) -> Result<(Self, WarriorDeckContext), Error> {
         let mut equipment_list = vec![];
+        let mut equipped: Vec<Item> = vec![];
         let mut props_list = vec![];
+        // initial equipments are equipped directly if their slots are free
         warrior.package_status.iter().for_each(|v| match v.class {
+            ItemClass::Equipment if !equipped.iter().any(|item| item.slot == v.slot) => {
+                equipped.push(v.clone())
+            }
             ItemClass::Equipment => equipment_list.push(v.clone()),
             ItemClass::Props => props_list.push(v.clone()),
         });
@@ -187,6 +200,7 @@ impl WarriorContext {
             draw_count: warrior.draw_count,
             physique: warrior.physique,
             equipment_list,
+            equipped,
             props_list,
             mounting_systems: vec![],
             warrior,
@@ -204,7 +218,19 @@ impl WarriorContext {
             player.props_list.append(&mut package);
             player_deck.add_deck(potion.deck_status);
         };
-        (player, player_deck)
+        // physique is the remaining capacity, which initial items take like collected ones
+        let weight = player
+            .equipped
+            .iter()
+            .chain(&player.equipment_list)
+            .chain(&player.props_list)
+            .map(|item| item.weight as u16)
+            .sum::<u16>();
+        if weight > player.physique as u16 {
+            return Err(Error::WarriorInsufficientPhysique);
+        }
+        player.physique -= weight as u8;
+        Ok((player, player_deck))
     }
 
     pub fn round_reset(&mut self) {
@@ -223,6 +249,58 @@ impl WarriorContext {
         self.draw_count = origin.draw_count;
         self.mounting_systems.clear();
     }
+
+    pub fn equipped_weight(&self) -> u16 {
+        self.equipped.iter().map(|item| item.weight as u16).sum()
+    }
+
+    // carried items have taken their physique once collected, so equipping only moves them
+    pub fn equip(&mut self, index: usize) -> Result<(), Error> {
+        let item = self
+            .equipment_list
+            .get(index)
+            .ok_or(Error::WarriorEquipmentOutOfIndex)?;
+        if self.equipped.iter().any(|v| v.slot == item.slot) {
+            return Err(Error::WarriorEquipmentSlotOccupied);
+        }
+        let item = self.equipment_list.remove(index);
+        self.equipped.push(item);
+        Ok(())
+    }
+
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Result<(), Error> {
+        let index = self
+            .equipped
+            .iter()
+            .position(|v| v.slot == slot)
+            .ok_or(Error::WarriorEquipmentSlotEmpty)?;
+        let item = self.equipped.remove(index);
+        self.equipment_list.push(item);
+        Ok(())
+    }
+
+    // replace the equipped item in the same slot, which goes back to `equipment_list`
+    pub fn swap_equipment(&mut self, index: usize) -> Result<(), Error> {
+        let item = self
+            .equipment_list
+            .get(index)
+            .ok_or(Error::WarriorEquipmentOutOfIndex)?;
+        let equipped_index = self
+            .equipped
+            .iter()
+            .position(|v| v.slot == item.slot)
+            .ok_or(Error::WarriorEquipmentSlotEmpty)?;
+        let item = self.equipment_list.remove(index);
+        let unequipped = core::mem::replace(&mut self.equipped[equipped_index], item);
+        self.equipment_list.push(unequipped);
+        Ok(())
+    }
+
+    pub fn is_silenced(&self) -> bool {
+        self.mounting_systems
+            .iter()
+            .any(|v| v.system.system_id == SystemId::TriggerSilence)
+    }
 }
 
 impl CtxAdaptor for WarriorContext {
@@ -18,6 +18,19 @@ pub enum Error {
     ResourceBrokenPartitionRange,
     #[cfg_attr(feature = "debug", error("item class was broken"))]
     ResourceBrokenItemClass,
+    #[cfg_attr(feature = "debug", error("equipment slot was broken"))]
+    ResourceBrokenEquipmentSlot,
+    #[cfg_attr(feature = "debug", error("enemy rank was broken"))]
+    ResourceBrokenEnemyRank,
+    #[cfg_attr(
+        feature = "debug",
+        error("enemy phases must be in descending hp percent")
+    )]
+    ResourceBrokenEnemyPhase,
+    #[cfg_attr(feature = "debug", error("action pool was broken"))]
+    ResourceBrokenActionPool,
+    #[cfg_attr(feature = "debug", error("action condition was broken"))]
+    ResourceBrokenActionCondition,
     #[cfg_attr(feature = "debug", error("target position was broken"))]
     ResourceBrokenTargetPosition,
     #[cfg_attr(feature = "debug", error("enemy strategy was broken"))]
@@ -34,6 +47,14 @@ pub enum Error {
     ResourceBrokenDurationCount,
     #[cfg_attr(feature = "debug", error("deck type was broken"))]
     ResourceBrokenDeckType,
+    #[cfg_attr(feature = "debug", error("challenge modifier class was broken"))]
+    ResourceBrokenModifierClass,
+    #[cfg_attr(feature = "debug", error("potion alchemy pool was broken"))]
+    ResourceBrokenAlchemyPool,
+    #[cfg_attr(feature = "debug", error("difficulty rule class was broken"))]
+    ResourceBrokenDifficultyClass,
+    #[cfg_attr(feature = "debug", error("curse card was not found in card pool"))]
+    ResourceBrokenCurseCard,
     #[cfg_attr(feature = "debug", error("duplicated system target"))]
     ResourceSystemTargetInSystemPoolDuplicated,
     #[cfg_attr(feature = "debug", error("invalid card selection in enemy"))]
@@ -46,14 +67,50 @@ pub enum Error {
     SceneInvalidMove,
     #[cfg_attr(feature = "debug", error("unexpected system result in map"))]
     SceneUnexpectedSystemReturn,
-    #[cfg_attr(feature = "debug", error("purchase or item offset out of index"))]
-    SceneUserImportOutOfIndex,
-    #[cfg_attr(feature = "debug", error("insufficient gold in purchase"))]
-    SceneMerchantInsufficientGold,
-    #[cfg_attr(feature = "debug", error("insufficient physique in purchase"))]
-    SceneMerchantInsufficientPhysique,
+    #[cfg_attr(
+        feature = "debug",
+        error("purchase or item offset out of index at import {0}")
+    )]
+    SceneUserImportOutOfIndex(usize),
+    #[cfg_attr(
+        feature = "debug",
+        error("duplicated purchase or item offset at import {0}")
+    )]
+    SceneUserImportDuplicated(usize),
+    #[cfg_attr(
+        feature = "debug",
+        error("insufficient gold in purchase at import {0}")
+    )]
+    SceneMerchantInsufficientGold(usize),
+    #[cfg_attr(
+        feature = "debug",
+        error("insufficient physique in purchase at import {0}")
+    )]
+    SceneMerchantInsufficientPhysique(usize),
     #[cfg_attr(feature = "debug", error("overwheelmed treasure chest picking"))]
     SceneTreasureChestOutOfBound,
+    #[cfg_attr(feature = "debug", error("card has no upgraded version"))]
+    SceneCardNotUpgradable,
+    #[cfg_attr(
+        feature = "debug",
+        error("campsite accepts at most one card to upgrade")
+    )]
+    SceneCampsiteInvalidChoice,
+    #[cfg_attr(feature = "debug", error("card service requires exactly one card"))]
+    SceneCardServiceInvalidChoice,
+    #[cfg_attr(feature = "debug", error("no item merchant at player point"))]
+    SceneMerchantNotFound,
+    #[cfg_attr(feature = "debug", error("equipment offset out of index"))]
+    WarriorEquipmentOutOfIndex,
+    #[cfg_attr(feature = "debug", error("equipment slot has been occupied"))]
+    WarriorEquipmentSlotOccupied,
+    #[cfg_attr(feature = "debug", error("nothing equipped in the slot"))]
+    WarriorEquipmentSlotEmpty,
+    #[cfg_attr(
+        feature = "debug",
+        error("insufficient physique to carry initial items")
+    )]
+    WarriorInsufficientPhysique,
     #[cfg_attr(feature = "debug", error("battle not start"))]
     BattleNotStarted,
     #[cfg_attr(feature = "debug", error("battle repeat start"))]
@@ -102,10 +159,48 @@ pub enum Error {
     BattleUnexpectedSystemDuration,
     #[cfg_attr(feature = "debug", error("battle unexpected system duration"))]
     BattleUnexpectedCardOffset,
+    #[cfg_attr(feature = "debug", error("system caster not found"))]
+    BattleSystemCasterNotFound,
+    #[cfg_attr(feature = "debug", error("system trigger source not found"))]
+    BattleSystemSourceNotFound,
+    #[cfg_attr(feature = "debug", error("special card is silenced"))]
+    BattleSpecialCardSilenced,
+    #[cfg_attr(feature = "debug", error("hand card is frozen"))]
+    BattleCardFrozen,
+    #[cfg_attr(feature = "debug", error("summoned enemy not found in resource pool"))]
+    BattleSummonEnemyNotFound,
+    #[cfg_attr(feature = "debug", error("unsupported fight stream version"))]
+    BattleStreamVersionUnsupported,
+    #[cfg_attr(feature = "debug", error("no player action to undo in this turn"))]
+    BattleUndoUnavailable,
+    #[cfg_attr(feature = "debug", error("battle limits must be positive"))]
+    BattleInvalidLimits,
+    #[cfg_attr(feature = "debug", error("exceed max player actions in this turn"))]
+    BattleTurnActionsExceeded,
+    #[cfg_attr(feature = "debug", error("battle requires non-empty enemy waves"))]
+    BattleEnemyWaveEmpty,
     #[cfg_attr(feature = "debug", error("missing system trigger type"))]
     SystemTriggerMissing,
+    #[cfg_attr(feature = "debug", error("system id is reserved for built-in systems"))]
+    SystemIdReserved,
+    #[cfg_attr(feature = "debug", error("system id has already been registered"))]
+    SystemIdDuplicated,
     #[cfg_attr(feature = "debug", error("deserialization error"))]
     DeserializeError,
     #[cfg_attr(feature = "debug", error("RNG rotation error"))]
     RngRotationError,
+    #[cfg_attr(feature = "debug", error("challenge modifiers mismatch with replay"))]
+    ChallengeModifierMismatch,
+    #[cfg_attr(
+        feature = "debug",
+        error("difficulty mismatch with replay or snapshot")
+    )]
+    DifficultyMismatch,
+    #[cfg_attr(feature = "debug", error("challenge day mismatch with replay"))]
+    ChallengeDayMismatch,
+    #[cfg_attr(
+        feature = "debug",
+        error("difficulty is above the highest level of difficulty rules")
+    )]
+    DifficultyOutOfRange,
 }
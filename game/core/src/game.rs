@@ -1,5 +1,5 @@
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use molecule::prelude::Entity;
 use rand::rngs::SmallRng;
 use rand::{RngCore, SeedableRng};
@@ -9,8 +9,20 @@ use crate::contexts::{WarriorContext, WarriorDeckContext};
 use crate::errors::Error;
 use crate::map::MapSkeleton;
 use crate::systems::SystemController;
-use crate::wrappings::{Point, Potion, Warrior};
+use crate::wrappings::{
+    randomized_selection, randomized_weighted_selection, Card, ChallengeModifier, DifficultyClass,
+    DifficultyRule, ModifierClass, Point, Potion, Warrior,
+};
 
+pub const DAILY_MODIFIER_COUNT: u8 = 2;
+
+// `day` is either the count of days since epoch, or a block number divided by the blocks of
+// a day, so that every challenger of the same day shares the seed
+pub fn daily_challenge_seed(day: u64) -> u64 {
+    SporeRng::new(day).next_u64()
+}
+
+#[derive(Clone)]
 pub struct SporeRng {
     rng: SmallRng,
     rotation_count: u16,
@@ -63,28 +75,130 @@ pub struct Game {
     pub controller: SystemController,
     pub map: MapSkeleton,
     pub potion: Option<Potion>,
+    pub modifiers: Vec<ChallengeModifier>,
+    pub challenge_day: Option<u64>,
+    pub difficulty: u8,
+    pub difficulty_rules: Vec<DifficultyRule>,
 }
 
 impl Game {
-    pub fn new(raw_resource_pool: &Vec<u8>, seed: u64) -> Result<Self, Error> {
+    // difficulty rules from level 1 to `difficulty` are applied, 0 stands for the base game,
+    // and levels above the highest rule level in pool are rejected
+    pub fn new(raw_resource_pool: &[u8], seed: u64, difficulty: u8) -> Result<Self, Error> {
         let resource_pool = generated::ResourcePool::from_compatible_slice(raw_resource_pool)
             .map_err(|_| Error::ResourceBroken)?;
+        let difficulty_rules = resource_pool
+            .difficulty_rules()
+            .into_iter()
+            .map(DifficultyRule::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let max_difficulty = difficulty_rules.iter().map(|v| v.level).max();
+        if difficulty > max_difficulty.unwrap_or_default() {
+            return Err(Error::DifficultyOutOfRange);
+        }
+        let difficulty_rules = difficulty_rules
+            .into_iter()
+            .filter(|v| (1..=difficulty).contains(&v.level))
+            .collect::<Vec<_>>();
         let rng = SporeRng::new(seed);
         let mut controller = SystemController::new(resource_pool, rng);
-        let map = MapSkeleton::randomized(&mut controller)?;
+        let mut map = MapSkeleton::randomized(&mut controller)?;
+        map.apply_difficulty(&difficulty_rules);
         Ok(Self {
             controller,
             map,
             potion: None,
+            modifiers: vec![],
+            challenge_day: None,
+            difficulty,
+            difficulty_rules,
         })
     }
 
+    // modifiers are rolled before the map, and both are fully determined by the day
+    pub fn new_daily_challenge(raw_resource_pool: &[u8], day: u64) -> Result<Self, Error> {
+        let resource_pool = generated::ResourcePool::from_compatible_slice(raw_resource_pool)
+            .map_err(|_| Error::ResourceBroken)?;
+        let mut rng = SporeRng::new(daily_challenge_seed(day));
+        let modifiers = randomized_weighted_selection(
+            resource_pool.modifier_pool().into_iter().collect(),
+            DAILY_MODIFIER_COUNT,
+            |_| 1,
+            &mut rng,
+        )
+        .into_iter()
+        .map(ChallengeModifier::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+        let mut controller = SystemController::new(resource_pool, rng);
+        let mut map = MapSkeleton::randomized(&mut controller)?;
+        map.apply_modifiers(&modifiers);
+        Ok(Self {
+            controller,
+            map,
+            potion: None,
+            modifiers,
+            challenge_day: Some(day),
+            difficulty: 0,
+            difficulty_rules: vec![],
+        })
+    }
+
+    // write the run settings into replay, the challenge day is 0 out of daily challenge, so
+    // that a verifier can rebuild the same game from replay alone
+    pub fn record_replay(
+        &self,
+        replay: generated::GameReplayBuilder,
+    ) -> generated::GameReplayBuilder {
+        let modifier_ids = self.modifiers.iter().map(|v| v.id).collect::<Vec<_>>();
+        replay
+            .modifiers(modifier_ids.into())
+            .difficulty(self.difficulty.into())
+            .challenge_day(self.challenge_day.unwrap_or_default().into())
+    }
+
+    // modifiers and day in replay must be the same as the ones rolled by the challenge
+    pub fn check_replay_modifiers(&self, replay: &generated::GameReplay) -> Result<(), Error> {
+        let challenge_day: u64 = replay.challenge_day().into();
+        if challenge_day != self.challenge_day.unwrap_or_default() {
+            return Err(Error::ChallengeDayMismatch);
+        }
+        let modifier_ids: Vec<u16> = replay.modifiers().into();
+        if modifier_ids.len() != self.modifiers.len()
+            || modifier_ids
+                .iter()
+                .zip(self.modifiers.iter())
+                .any(|(id, modifier)| *id != modifier.id)
+        {
+            return Err(Error::ChallengeModifierMismatch);
+        }
+        Ok(())
+    }
+
+    // contract verifies a run at the difficulty recorded in both replay and snapshot
+    pub fn check_difficulty(
+        &self,
+        replay: &generated::GameReplay,
+        snapshot: &generated::GameSnapshot,
+    ) -> Result<(), Error> {
+        let replay_difficulty: u8 = replay.difficulty().into();
+        let snapshot_difficulty: u8 = snapshot.difficulty().into();
+        if replay_difficulty != self.difficulty || snapshot_difficulty != self.difficulty {
+            return Err(Error::DifficultyMismatch);
+        }
+        Ok(())
+    }
+
+    fn has_modifier(&self, class: ModifierClass) -> bool {
+        self.modifiers.iter().any(|v| v.class == class)
+    }
+
     pub fn new_session<'a>(
         &mut self,
         player_id: u16,
         player_point: Point,
         raw_potion: Option<Vec<u8>>,
     ) -> Result<(WarriorContext, WarriorDeckContext), Error> {
+        let random_potion = self.has_modifier(ModifierClass::RandomPotion);
         let resource_pool = &self.controller.resource_pool;
         let mut rng = &mut self.controller.rng;
         let potion = {
@@ -92,6 +206,13 @@ impl Game {
                 let potion = generated::Potion::from_compatible_slice(&raw_potion)
                     .map_err(|_| Error::ResourceBroken)?;
                 Some(Potion::randomized(&resource_pool, potion, &mut rng)?)
+            } else if random_potion {
+                let alchemy_pool = resource_pool.alchemy_pool();
+                let alchemy = randomized_selection(alchemy_pool.len(), alchemy_pool, 1, rng)
+                    .first()
+                    .cloned()
+                    .ok_or(Error::ResourceBrokenAlchemyPool)?;
+                Some(Potion::brewed(resource_pool, alchemy, &mut rng)?)
             } else {
                 None
             }
@@ -104,9 +225,24 @@ impl Game {
                 .ok_or(Error::ResourceBrokenCharactorId)?;
             Warrior::randomized(resource_pool, warrior, rng)?
         };
+        let curse_cards = self
+            .difficulty_rules
+            .iter()
+            .filter(|v| v.class == DifficultyClass::CurseCard)
+            .map(|rule| {
+                let card = resource_pool
+                    .card_pool()
+                    .into_iter()
+                    .find(|v| u16::from(v.id()) == rule.value)
+                    .ok_or(Error::ResourceBrokenCurseCard)?;
+                Card::randomized(resource_pool, card, rng)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let (player, mut player_deck) = WarriorContext::new(warrior, potion.clone())?;
+        player_deck.add_deck(curse_cards);
         self.potion = potion;
         self.map.place_player(player_point, true)?;
-        Ok(WarriorContext::new(warrior, self.potion.clone()))
+        Ok((player, player_deck))
     }
 
     pub fn recover_session<'a>(
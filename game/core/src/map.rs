@@ -1,33 +1,57 @@
 extern crate alloc;
 use alloc::{vec, vec::Vec};
 use core::cmp::{max, min};
+use rand::RngCore;
 
 use crate::battle::pve::MapBattlePVE;
 use crate::battle::traits::{FightLog, SimplePVE};
 use crate::contexts::{CardContext, CtxAdaptor, WarriorContext, WarriorDeckContext};
 use crate::errors::Error;
-use crate::systems::{Command, SystemController, SystemReturn};
+use crate::systems::{Command, SystemController, SystemObjects, SystemReturn};
 use crate::wrappings::{
-    randomized_selection, Card, Item, ItemClass, LevelNode, LevelPartition, Node, Point, System,
+    randomized_selection, Card, ChallengeModifier, DifficultyClass, DifficultyRule, Enemy, Item,
+    ItemClass, LevelNode, LevelPartition, ModifierClass, Node, Point, System,
 };
 
 #[cfg(feature = "json_serde")]
 use serde::Serialize;
 
+fn percent_up(value: u16, percent: u16) -> u16 {
+    let value = value as u32 * (100 + percent as u32) / 100;
+    min(value, u16::MAX as u32) as u16
+}
+
+// enemy classes of difficulty rules, shared by the map enemies and the ones summoned in battle
+pub(crate) fn apply_enemy_difficulty(enemy: &mut Enemy, rules: &[DifficultyRule]) {
+    for rule in rules {
+        match rule.class {
+            DifficultyClass::EnemyHpPercentUp => enemy.hp = percent_up(enemy.hp, rule.value),
+            DifficultyClass::EnemyAttackUp => {
+                let attack = min(rule.value, u8::MAX as u16) as u8;
+                enemy.attack = enemy.attack.saturating_add(attack);
+            }
+            _ => {}
+        }
+    }
+}
+
+// enemy classes of challenge modifiers, shared by the map enemies and the summoned ones
+pub(crate) fn apply_enemy_modifiers(enemy: &mut Enemy, modifiers: &[ChallengeModifier]) {
+    for modifier in modifiers {
+        if modifier.class == ModifierClass::EnemyHpPercentUp {
+            enemy.hp = percent_up(enemy.hp, modifier.value);
+        }
+    }
+}
+
 fn run_context(
     player: &mut WarriorContext,
     system: System,
     controller: &mut SystemController,
 ) -> Result<Vec<FightLog>, Error> {
     let player_offset = player.offset();
-    let mut system_contexts: Vec<&mut dyn CtxAdaptor> = vec![player];
-    let system_return = controller.system_call(
-        system.into(),
-        player_offset,
-        vec![],
-        &mut system_contexts,
-        None,
-    )?;
+    let mut system_objects = SystemObjects::new(player_offset, &[], vec![player])?;
+    let system_return = controller.system_call(system.into(), &mut system_objects, None)?;
     if let SystemReturn::Continue(cmds) = system_return {
         let mut context_logs = vec![];
         cmds.into_iter().for_each(|v| {
@@ -41,53 +65,130 @@ fn run_context(
     }
 }
 
+// errors of user imports carry the position of the failed one in `user_imported`
+fn check_selection(user_imported: &[usize], stock_size: usize) -> Result<(), Error> {
+    user_imported.iter().enumerate().try_for_each(|(i, index)| {
+        if *index >= stock_size {
+            return Err(Error::SceneUserImportOutOfIndex(i));
+        }
+        if user_imported[..i].contains(index) {
+            return Err(Error::SceneUserImportDuplicated(i));
+        }
+        Ok(())
+    })
+}
+
+// validate gold and physique of the whole purchase before charging anything
+fn check_purchase(
+    player: &WarriorContext,
+    costs: impl Iterator<Item = (u16, u8)>,
+) -> Result<(u16, u8), Error> {
+    let (mut gold, mut physique) = (0u16, 0u8);
+    for (i, (price, weight)) in costs.enumerate() {
+        gold = gold.saturating_add(price);
+        if gold > player.gold {
+            return Err(Error::SceneMerchantInsufficientGold(i));
+        }
+        physique = physique.saturating_add(weight);
+        if physique > player.physique {
+            return Err(Error::SceneMerchantInsufficientPhysique(i));
+        }
+    }
+    Ok((gold, physique))
+}
+
+// remove taken goods from node stock, so they cannot be taken again in the next visit
+fn consume_stock<T>(stock: &mut Vec<T>, mut taken: Vec<usize>) -> Vec<T> {
+    taken.sort_unstable_by(|a, b| b.cmp(a));
+    taken.into_iter().map(|index| stock.remove(index)).collect()
+}
+
 fn purchase_cards(
     player: &mut WarriorContext,
     player_deck: &mut WarriorDeckContext,
     user_imported: Vec<usize>,
-    cards: &Vec<Card>,
-) -> Result<Vec<()>, Error> {
-    user_imported
-        .into_iter()
-        .map(|index| {
-            let card = cards.get(index).ok_or(Error::SceneUserImportOutOfIndex)?;
-            if card.price > player.gold {
-                return Err(Error::SceneMerchantInsufficientGold);
-            }
-            player.gold -= card.price;
-            player_deck.deck.push(CardContext::new(card.clone()));
-            Ok(())
-        })
-        .collect::<Result<Vec<_>, _>>()
+    cards: &mut Vec<Card>,
+) -> Result<(), Error> {
+    check_selection(&user_imported, cards.len())?;
+    let (gold, _) = check_purchase(
+        player,
+        user_imported.iter().map(|index| (cards[*index].price, 0)),
+    )?;
+    player.gold -= gold;
+    user_imported.iter().for_each(|index| {
+        player_deck
+            .deck
+            .push(CardContext::new(cards[*index].clone()))
+    });
+    consume_stock(cards, user_imported);
+    Ok(())
+}
+
+fn upgrade_card(
+    player_deck: &mut WarriorDeckContext,
+    index: usize,
+    controller: &mut SystemController,
+) -> Result<usize, Error> {
+    let context = player_deck
+        .deck
+        .get_mut(index)
+        .ok_or(Error::SceneUserImportOutOfIndex(0))?;
+    let card = context
+        .card
+        .upgraded(&controller.resource_pool, &mut controller.rng)?;
+    context.upgrade(card);
+    Ok(context.offset())
+}
+
+fn pay_card_service(
+    player: &mut WarriorContext,
+    price: u16,
+    price_increase: u16,
+    used_count: u8,
+) -> Result<(), Error> {
+    let price = price.saturating_add(price_increase.saturating_mul(used_count as u16));
+    let (gold, _) = check_purchase(player, [(price, 0)].into_iter())?;
+    player.gold -= gold;
+    Ok(())
+}
+
+fn select_deck_card(
+    player_deck: &WarriorDeckContext,
+    user_imported: Vec<usize>,
+) -> Result<usize, Error> {
+    let [index] = user_imported.as_slice() else {
+        return Err(Error::SceneCardServiceInvalidChoice);
+    };
+    check_selection(&user_imported, player_deck.deck.len())?;
+    Ok(*index)
 }
 
 fn collect_items(
     player: &mut WarriorContext,
     user_imported: Vec<usize>,
-    items: &Vec<Item>,
+    items: &mut Vec<Item>,
     purchase: bool,
-) -> Result<Vec<()>, Error> {
-    user_imported
-        .into_iter()
-        .map(|index| {
-            let item = items.get(index).ok_or(Error::SceneUserImportOutOfIndex)?;
-            if purchase {
-                if item.price > player.gold {
-                    return Err(Error::SceneMerchantInsufficientGold);
-                }
-                player.gold -= item.price;
-                if item.weight > player.physique {
-                    return Err(Error::SceneMerchantInsufficientPhysique);
-                }
-                player.physique -= item.weight;
-            }
-            match item.class {
-                ItemClass::Equipment => player.equipment_list.push(item.clone()),
-                ItemClass::Props => player.props_list.push(item.clone()),
-            }
-            Ok(())
-        })
-        .collect::<Result<Vec<_>, _>>()
+) -> Result<(), Error> {
+    check_selection(&user_imported, items.len())?;
+    // free items take physique as well, since selling them refunds it
+    let (gold, physique) = check_purchase(
+        player,
+        user_imported.iter().map(|index| {
+            let item = &items[*index];
+            (if purchase { item.price } else { 0 }, item.weight)
+        }),
+    )?;
+    player.gold -= gold;
+    player.physique -= physique;
+    user_imported.iter().for_each(|index| {
+        let item = &items[*index];
+        match item.class {
+            ItemClass::Equipment => player.equipment_list.push(item.clone()),
+            ItemClass::Props => player.props_list.push(item.clone()),
+        }
+    });
+    consume_stock(items, user_imported);
+    Ok(())
 }
 
 #[cfg_attr(feature = "json_serde", derive(Serialize))]
@@ -106,6 +207,8 @@ pub struct MapSkeleton {
     pub height: i16,
     pub skeleton: Vec<LevelNode>,
     pub player_point: Point,
+    pub difficulty_rules: Vec<DifficultyRule>,
+    pub modifiers: Vec<ChallengeModifier>,
 }
 
 impl<'a> MapSkeleton {
@@ -117,15 +220,16 @@ impl<'a> MapSkeleton {
             .first()
             .cloned()
             .ok_or(Error::ResourceBrokenScenePool)?;
+        let rarity = scene.rarity().into();
         let mut skeleton = scene
             .fixed_nodes()
             .into_iter()
-            .map(|node| LevelNode::fix_randomized(resource_pool, node, rng))
+            .map(|node| LevelNode::fix_randomized(resource_pool, node, rarity, rng))
             .collect::<Result<Vec<_>, _>>()?;
         scene
             .partition_list()
             .into_iter()
-            .map(|partition| LevelPartition::randomized(resource_pool, partition, rng))
+            .map(|partition| LevelPartition::randomized(resource_pool, partition, rarity, rng))
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
             .for_each(|mut level| skeleton.append(&mut level.nodes));
@@ -135,9 +239,79 @@ impl<'a> MapSkeleton {
             height: u8::from(scene.height()) as i16,
             skeleton,
             player_point: Point::default(),
+            difficulty_rules: vec![],
+            modifiers: vec![],
         })
     }
 
+    // challenge modifiers reshape the generated map, so every challenger meets the same one
+    pub fn apply_modifiers(&mut self, modifiers: &[ChallengeModifier]) {
+        self.enemies_mut()
+            .into_iter()
+            .for_each(|enemy| apply_enemy_modifiers(enemy, modifiers));
+        self.modifiers.extend(modifiers.iter().cloned());
+        for modifier in modifiers {
+            match modifier.class {
+                ModifierClass::EnemyHpPercentUp => {}
+                ModifierClass::NoMerchant => self.skeleton.retain(|level| {
+                    !matches!(level.node, Node::ItemMerchant(_) | Node::CardMerchant(_))
+                }),
+                ModifierClass::RandomPotion => {}
+            }
+        }
+    }
+
+    // rules below the difficulty are all active, and the same classes stack up, except
+    // curse cards which go to the player deck instead
+    //
+    // enemy rules are kept in map, so battles started from it scale their summoned enemies too
+    pub fn apply_difficulty(&mut self, rules: &[DifficultyRule]) {
+        self.enemies_mut()
+            .into_iter()
+            .for_each(|enemy| apply_enemy_difficulty(enemy, rules));
+        self.difficulty_rules.extend(rules.iter().cloned());
+        for rule in rules {
+            match rule.class {
+                DifficultyClass::EnemyHpPercentUp | DifficultyClass::EnemyAttackUp => {}
+                DifficultyClass::MerchantPricePercentUp => {
+                    self.skeleton
+                        .iter_mut()
+                        .for_each(|level| match &mut level.node {
+                            Node::ItemMerchant(items) => items.iter_mut().for_each(|item| {
+                                item.price = percent_up(item.price, rule.value);
+                            }),
+                            Node::CardMerchant(cards) => cards.iter_mut().for_each(|card| {
+                                card.price = percent_up(card.price, rule.value);
+                            }),
+                            _ => {}
+                        })
+                }
+                DifficultyClass::RecoverPercentDown => {
+                    let value = min(rule.value, u8::MAX as u16) as u8;
+                    self.skeleton.iter_mut().for_each(|level| {
+                        if let Node::RecoverPoint(percent) = &mut level.node {
+                            *percent = percent.saturating_sub(value);
+                        }
+                    });
+                }
+                DifficultyClass::CurseCard => {}
+            }
+        }
+    }
+
+    fn enemies_mut(&mut self) -> Vec<&mut Enemy> {
+        self.skeleton
+            .iter_mut()
+            .flat_map(|level| match &mut level.node {
+                Node::Enemy(waves) => waves.iter_mut().flatten().collect(),
+                Node::EliteEnemy(enemies) | Node::BossEnemy(enemies) => {
+                    enemies.iter_mut().collect()
+                }
+                _ => vec![],
+            })
+            .collect()
+    }
+
     pub fn place_player(
         &mut self,
         player_point: Point,
@@ -163,6 +337,48 @@ impl<'a> MapSkeleton {
         player: &WarriorContext,
         peak_point: Point,
     ) -> Result<Option<&LevelNode>, Error> {
+        let index = self.peak_upcoming_index(player, peak_point)?;
+        Ok(index.map(|i| &self.skeleton[i]))
+    }
+
+    pub fn sell_items(
+        &mut self,
+        player: &mut WarriorContext,
+        class: ItemClass,
+        user_imported: Vec<usize>,
+    ) -> Result<u16, Error> {
+        let player_point = self.player_point;
+        let Some(Node::ItemMerchant(stock)) = self
+            .skeleton
+            .iter_mut()
+            .find(|level| level.point.contains(&player_point))
+            .map(|level| &mut level.node)
+        else {
+            return Err(Error::SceneMerchantNotFound);
+        };
+        let package = match class {
+            ItemClass::Equipment => &mut player.equipment_list,
+            ItemClass::Props => &mut player.props_list,
+        };
+        check_selection(&user_imported, package.len())?;
+        let (mut gold, mut physique) = (0u16, 0u8);
+        consume_stock(package, user_imported)
+            .into_iter()
+            .for_each(|item| {
+                gold = gold.saturating_add(item.sell_price());
+                physique = physique.saturating_add(item.weight);
+                stock.push(item);
+            });
+        player.gold = player.gold.saturating_add(gold);
+        player.physique = player.physique.saturating_add(physique);
+        Ok(gold)
+    }
+
+    fn peak_upcoming_index(
+        &self,
+        player: &WarriorContext,
+        peak_point: Point,
+    ) -> Result<Option<usize>, Error> {
         if !self.contains(&peak_point) {
             return Err(Error::ScenePlayerPointBeyondMap);
         }
@@ -176,7 +392,7 @@ impl<'a> MapSkeleton {
         } else {
             peaked_node = nodes
                 .into_iter()
-                .find(|node| node.point.contains(&peak_point));
+                .find(|i| self.skeleton[*i].point.contains(&peak_point));
         }
         Ok(peaked_node)
     }
@@ -189,24 +405,47 @@ impl<'a> MapSkeleton {
         user_imported: Vec<usize>,
         controller: &mut SystemController,
     ) -> Result<MoveResult, Error> {
-        self.player_point = player_point;
-        let Some(level) = self.peak_upcoming_movment(player, player_point)? else {
-            return Ok(MoveResult::Skip);
+        let result = match self.peak_upcoming_index(player, player_point)? {
+            Some(index) => self.interact(index, player, player_deck, user_imported, controller)?,
+            None => MoveResult::Skip,
         };
+        // the player only moves once the node interaction succeeds
+        self.player_point = player_point;
+        Ok(result)
+    }
+
+    fn interact(
+        &mut self,
+        index: usize,
+        player: &mut WarriorContext,
+        player_deck: &mut WarriorDeckContext,
+        user_imported: Vec<usize>,
+        controller: &mut SystemController,
+    ) -> Result<MoveResult, Error> {
         let mut map_logs = vec![];
-        match &level.node {
+        match &mut self.skeleton[index].node {
             Node::Barrier | Node::StartingPoint => return Err(Error::SceneInvalidMove),
             Node::TargetingPoint => return Ok(MoveResult::Complete),
             Node::RecoverPoint(percent) => {
                 let max_hp = player.warrior.hp;
-                let hp_recover = min(max_hp * (*percent / 100u8) as u16, max_hp - player.hp);
+                let hp_recover = min(
+                    max_hp as u32 * *percent as u32 / 100,
+                    max_hp.saturating_sub(player.hp) as u32,
+                ) as u16;
                 player.hp += hp_recover;
                 map_logs.push(FightLog::RecoverHp(hp_recover));
             }
-            Node::Campsite(context) => {
-                let mut logs = run_context(player, context.clone(), controller)?;
-                map_logs.append(&mut logs);
-            }
+            Node::Campsite(context) => match user_imported.as_slice() {
+                [] => {
+                    let mut logs = run_context(player, context.clone(), controller)?;
+                    map_logs.append(&mut logs);
+                }
+                [index] => {
+                    let offset = upgrade_card(player_deck, *index, controller)?;
+                    map_logs.push(FightLog::UpgradeCard(offset));
+                }
+                _ => return Err(Error::SceneCampsiteInvalidChoice),
+            },
             Node::Unknown(contexts) => {
                 contexts
                     .iter()
@@ -217,9 +456,19 @@ impl<'a> MapSkeleton {
                     })
                     .collect::<Result<_, _>>()?;
             }
-            Node::Enemy(enemies) => {
-                let fight =
-                    MapBattlePVE::create(player.clone(), player_deck.clone(), enemies.clone())?;
+            Node::Enemy(waves) => {
+                let mut fight =
+                    MapBattlePVE::create(player.clone(), player_deck.clone(), waves.clone())?;
+                fight.set_enemy_scaling(self.difficulty_rules.clone(), self.modifiers.clone());
+                return Ok(MoveResult::Fight(fight));
+            }
+            Node::EliteEnemy(enemies) | Node::BossEnemy(enemies) => {
+                let mut fight = MapBattlePVE::create(
+                    player.clone(),
+                    player_deck.clone(),
+                    vec![enemies.clone()],
+                )?;
+                fight.set_enemy_scaling(self.difficulty_rules.clone(), self.modifiers.clone());
                 return Ok(MoveResult::Fight(fight));
             }
             Node::ItemMerchant(items) => {
@@ -228,11 +477,30 @@ impl<'a> MapSkeleton {
             Node::CardMerchant(cards) => {
                 purchase_cards(player, player_deck, user_imported, cards)?;
             }
+            Node::CardShrine(price, price_increase) => {
+                let index = select_deck_card(player_deck, user_imported)?;
+                pay_card_service(player, *price, *price_increase, player_deck.removal_count)?;
+                let context = player_deck.deck.remove(index);
+                player_deck.removal_count = player_deck.removal_count.saturating_add(1);
+                map_logs.push(FightLog::RemoveCard(context.offset()));
+            }
+            Node::CardAltar(price, price_increase, cards) => {
+                let index = select_deck_card(player_deck, user_imported)?;
+                pay_card_service(player, *price, *price_increase, player_deck.transform_count)?;
+                let offset = player_deck.deck[index].offset();
+                let mut card = cards[controller.rng.next_u32() as usize % cards.len()].clone();
+                card.offset = offset;
+                player_deck.deck[index] = CardContext::new(card);
+                player_deck.transform_count = player_deck.transform_count.saturating_add(1);
+                map_logs.push(FightLog::TransformCard(offset));
+            }
             Node::TreasureChest(items, pick_count) => {
                 if user_imported.len() > *pick_count as usize {
                     return Err(Error::SceneTreasureChestOutOfBound);
                 }
+                let picked = user_imported.len() as u8;
                 collect_items(player, user_imported, items, false)?;
+                *pick_count -= picked;
             }
         }
         Ok(MoveResult::MapLogs(map_logs))
@@ -263,10 +531,12 @@ impl<'a> MapSkeleton {
         points
     }
 
-    fn filter_nonempty_nodes(&self, points: &Vec<Point>) -> Vec<&LevelNode> {
+    fn filter_nonempty_nodes(&self, points: &Vec<Point>) -> Vec<usize> {
         self.skeleton
             .iter()
-            .filter(|node| points.iter().any(|point| node.point.contains(point)))
+            .enumerate()
+            .filter(|(_, node)| points.iter().any(|point| node.point.contains(point)))
+            .map(|(i, _)| i)
             .collect()
     }
 }
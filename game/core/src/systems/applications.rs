@@ -5,11 +5,12 @@ use core::cmp::min;
 use crate::battle::traits::FightLog;
 use crate::contexts::{ContextType, CtxAdaptor};
 use crate::errors::Error;
-use crate::systems::MAX_WEAK_COUNT;
+use crate::systems::{MAX_WEAK_COUNT, PIERCE_ARMOR, PIERCE_SHIELD};
 
 pub fn attack_apply(
     logs: &mut Vec<FightLog>,
     mut value: u16,
+    source: usize,
     object: &mut &mut dyn CtxAdaptor,
 ) -> Result<(), Error> {
     let damage = value;
@@ -52,7 +53,42 @@ pub fn attack_apply(
         }
         ContextType::Card => return Ok(()),
     };
-    logs.push(FightLog::SystemDamage(object.offset(), damage));
+    logs.push(FightLog::SystemDamage(source, object.offset(), damage));
+    Ok(())
+}
+
+pub fn fixed_damage_apply(
+    logs: &mut Vec<FightLog>,
+    value: u16,
+    pierce: u16,
+    object: &mut &mut dyn CtxAdaptor,
+    log: fn(usize, u16) -> FightLog,
+) -> Result<(), Error> {
+    let offset = object.offset();
+    let (hp, armor, shield) = match object.context_type() {
+        ContextType::Warrior => {
+            let warrior = object.warrior()?;
+            (&mut warrior.hp, &mut warrior.armor, &mut warrior.shield)
+        }
+        ContextType::Enemy => {
+            let enemy = object.enemy()?;
+            (&mut enemy.hp, &mut enemy.armor, &mut enemy.shield)
+        }
+        ContextType::Card => return Ok(()),
+    };
+    let mut damage = value;
+    if pierce & PIERCE_SHIELD == 0 {
+        let absorbed = min(damage, *shield);
+        *shield -= absorbed;
+        damage -= absorbed;
+    }
+    if pierce & PIERCE_ARMOR == 0 {
+        let absorbed = min(damage, *armor);
+        *armor -= absorbed;
+        damage -= absorbed;
+    }
+    *hp = hp.saturating_sub(damage);
+    logs.push(log(offset, value));
     Ok(())
 }
 
@@ -4,6 +4,7 @@ use alloc::{vec, vec::Vec};
 use rand::RngCore;
 use spore_warriors_generated as generated;
 
+use crate::apply_system;
 use crate::battle::traits::FightLog;
 use crate::contexts::{CardContext, ContextType, CtxAdaptor, SystemContext, WarriorDeckContext};
 use crate::errors::Error;
@@ -13,9 +14,8 @@ use crate::systems::applications::{
     draw_count_down_apply, draw_count_up_apply, healing_apply, max_hp_down_apply, max_hp_up_apply,
     power_cost_down_apply, shield_up_apply,
 };
-use crate::systems::{Command, SystemInput, SystemReturn};
+use crate::systems::{Command, SystemInput, SystemObjects, SystemReturn};
 use crate::wrappings::{Card, Value};
-use crate::{apply_system, filter_objects};
 
 #[derive(PartialEq)]
 enum DeckType {
@@ -42,15 +42,21 @@ pub fn attack(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
-    let mut logs = vec![];
+    if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
+        return Ok(SystemReturn::Continue(vec![]));
+    }
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
-    apply_system!(logs, iter, input, objects, u16, attack_apply);
+    let Some(Value(damage)) = iter.next() else {
+        return Err(Error::BattleUnexpectedSystemArgs);
+    };
+    let mut logs = vec![];
+    let source = objects.caster_offset();
+    for object in objects.targets() {
+        attack_apply(&mut logs, *damage, source, object)?;
+    }
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
 
@@ -59,9 +65,7 @@ pub fn multiple_attack(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -72,10 +76,10 @@ pub fn multiple_attack(
         return Err(Error::BattleUnexpectedSystemArgs);
     };
     let mut logs = vec![];
-    let objects = filter_objects!(objects, targets);
-    for object in objects {
+    let source = objects.caster_offset();
+    for object in objects.targets() {
         (0..*count)
-            .map(|_| attack_apply(&mut logs, *damage, object))
+            .map(|_| attack_apply(&mut logs, *damage, source, object))
             .collect::<Result<_, _>>()?;
     }
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
@@ -86,14 +90,12 @@ pub fn armor_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u16, armor_up_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -103,14 +105,12 @@ pub fn armor_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -129,14 +129,12 @@ pub fn shield_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u16, shield_up_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -146,14 +144,12 @@ pub fn shield_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -172,14 +168,12 @@ pub fn healing(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u16, healing_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -189,14 +183,12 @@ pub fn draw_count_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u8, draw_count_up_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -206,14 +198,12 @@ pub fn draw_count_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u8, draw_count_down_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -223,14 +213,12 @@ pub fn max_hp_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u16, max_hp_up_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -240,14 +228,12 @@ pub fn max_hp_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u16, max_hp_down_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -257,14 +243,12 @@ pub fn attack_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -283,14 +267,12 @@ pub fn attack_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -309,14 +291,12 @@ pub fn defense_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -335,14 +315,12 @@ pub fn defense_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -361,14 +339,12 @@ pub fn attack_weak_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u8, attack_power_weak_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -378,14 +354,12 @@ pub fn attack_weak_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -404,14 +378,12 @@ pub fn defense_weak_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u8, defense_power_weak_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -421,14 +393,12 @@ pub fn defense_weak_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(
         logs,
         iter,
@@ -447,9 +417,7 @@ pub fn draw_cards(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    _: Vec<usize>,
-    _: &mut [&mut dyn CtxAdaptor],
+    _: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -469,9 +437,7 @@ pub fn draw_select_cards(
     resource_pool: &generated::ResourcePool,
     rng: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    _: Vec<usize>,
-    _: &mut [&mut dyn CtxAdaptor],
+    _: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -552,9 +518,7 @@ pub fn discard_cards(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    _: Vec<usize>,
-    _: &mut [&mut dyn CtxAdaptor],
+    _: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -573,9 +537,7 @@ pub fn discard_select_cards(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    _: Vec<usize>,
-    _: &mut [&mut dyn CtxAdaptor],
+    _: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -593,14 +555,12 @@ pub fn card_power_cost_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     let mut logs = vec![];
     let mut iter = ctx.system.args.iter();
-    let objects = filter_objects!(objects, targets);
+    let objects = objects.targets();
     apply_system!(logs, iter, input, objects, u8, power_cost_down_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -610,9 +570,7 @@ pub fn change_random_card_cost(
     _: &generated::ResourcePool,
     rng: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    _: Vec<usize>,
-    _: &mut [&mut dyn CtxAdaptor],
+    _: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
@@ -635,3 +593,30 @@ pub fn change_random_card_cost(
     };
     Ok(SystemReturn::RequireDeckChange(Box::new(operator)))
 }
+
+// summon enemies by resource id, the battle only randomizes the ones within the alive cap
+pub fn summon_enemies(
+    resource_pool: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    _: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
+        return Ok(SystemReturn::Continue(vec![]));
+    }
+    let mut iter = ctx.system.args.iter();
+    let (Some(Value(enemy_id)), Some(Value(count)), Some(Value(cap))) =
+        (iter.next(), iter.next(), iter.next())
+    else {
+        return Err(Error::BattleUnexpectedSystemArgs);
+    };
+    let enemy = resource_pool
+        .enemy_pool()
+        .into_iter()
+        .find(|v| u16::from(v.id()) == *enemy_id)
+        .ok_or(Error::BattleSummonEnemyNotFound)?;
+    Ok(SystemReturn::Continue(vec![Command::SummonEnemies(
+        enemy, *count, *cap,
+    )]))
+}
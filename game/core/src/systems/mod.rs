@@ -1,7 +1,7 @@
 extern crate alloc;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use spore_warriors_generated as generated;
 
 use crate::battle::traits::FightLog;
@@ -10,10 +10,15 @@ use crate::errors::Error;
 use crate::game::SporeRng;
 use crate::wrappings::SystemId;
 
+#[cfg(feature = "custom_system")]
+use crate::wrappings::CUSTOM_SYSTEM_ID_START;
+
 #[cfg(feature = "json_serde")]
 use serde::Serialize;
 
 const MAX_WEAK_COUNT: u8 = 10;
+const PIERCE_SHIELD: u16 = 0b01;
+const PIERCE_ARMOR: u16 = 0b10;
 
 mod applications;
 mod instant;
@@ -23,6 +28,7 @@ pub enum Command {
     AddLogs(Vec<FightLog>),
     DrawCards(u8),
     DiscardHandCards(u8, bool),
+    SummonEnemies(generated::Enemy, u16, u16),
 }
 
 type DeckOperator = Box<dyn FnMut(&mut WarriorDeckContext) -> Vec<FightLog>>;
@@ -40,13 +46,127 @@ pub enum SystemInput {
     Trigger(FightLog),
 }
 
+pub struct SystemObjects<'a> {
+    caster_offset: usize,
+    source_offset: Option<usize>,
+    caster: Option<&'a mut dyn CtxAdaptor>,
+    source: Option<&'a mut dyn CtxAdaptor>,
+    targets: Vec<&'a mut dyn CtxAdaptor>,
+}
+
+impl<'a> SystemObjects<'a> {
+    // caster will be shared with targets if it's targeted by itself
+    pub fn new(
+        caster: usize,
+        targets: &[usize],
+        objects: Vec<&'a mut dyn CtxAdaptor>,
+    ) -> Result<Self, Error> {
+        Self::with_source(caster, None, targets, objects)
+    }
+
+    // source is the object that produced the trigger log, e.g. the attacker of a damage log
+    pub fn with_source(
+        caster: usize,
+        source: Option<usize>,
+        targets: &[usize],
+        objects: Vec<&'a mut dyn CtxAdaptor>,
+    ) -> Result<Self, Error> {
+        let mut caster_object = None;
+        let mut source_object = None;
+        let mut target_objects = vec![];
+        for object in objects {
+            let offset = object.offset();
+            if targets.contains(&offset) {
+                target_objects.push(object);
+            } else if offset == caster {
+                caster_object = Some(object);
+            } else if Some(offset) == source {
+                source_object = Some(object);
+            }
+        }
+        if caster_object.is_none() && !targets.contains(&caster) {
+            return Err(Error::BattleSystemCasterNotFound);
+        }
+        Ok(Self {
+            caster_offset: caster,
+            source_offset: source,
+            caster: caster_object,
+            source: source_object,
+            targets: target_objects,
+        })
+    }
+
+    pub fn caster_offset(&self) -> usize {
+        self.caster_offset
+    }
+
+    pub fn caster(&mut self) -> Result<&mut &'a mut dyn CtxAdaptor, Error> {
+        let offset = self.caster_offset;
+        match self.caster.as_mut() {
+            Some(caster) => Ok(caster),
+            None => self
+                .targets
+                .iter_mut()
+                .find(|v| v.offset() == offset)
+                .ok_or(Error::BattleSystemCasterNotFound),
+        }
+    }
+
+    pub fn source(&mut self) -> Result<&mut &'a mut dyn CtxAdaptor, Error> {
+        let offset = self
+            .source_offset
+            .ok_or(Error::BattleSystemSourceNotFound)?;
+        match self.source.as_mut() {
+            Some(source) => Ok(source),
+            None => self
+                .caster
+                .iter_mut()
+                .chain(self.targets.iter_mut())
+                .find(|v| v.offset() == offset)
+                .ok_or(Error::BattleSystemSourceNotFound),
+        }
+    }
+
+    pub fn targets(&mut self) -> &mut [&'a mut dyn CtxAdaptor] {
+        &mut self.targets
+    }
+
+    // split into caster and the other targets, so both sides can be mutated at the same time
+    pub fn split(
+        &mut self,
+    ) -> Result<
+        (
+            &mut &'a mut dyn CtxAdaptor,
+            Vec<&mut &'a mut dyn CtxAdaptor>,
+        ),
+        Error,
+    > {
+        let offset = self.caster_offset;
+        let (caster, targets) = match self.caster.as_mut() {
+            Some(caster) => (Some(caster), self.targets.iter_mut().collect()),
+            None => {
+                let mut caster = None;
+                let mut targets = vec![];
+                for object in self.targets.iter_mut() {
+                    if object.offset() == offset {
+                        caster = Some(object);
+                    } else {
+                        targets.push(object);
+                    }
+                }
+                (caster, targets)
+            }
+        };
+        let caster = caster.ok_or(Error::BattleSystemCasterNotFound)?;
+        Ok((caster, targets))
+    }
+}
+
 pub type SystemCallback = fn(
     &generated::ResourcePool,
     &mut SporeRng,
     SystemContext,
-    usize,
-    Vec<usize>,
-    &mut [&mut dyn CtxAdaptor],
+    &mut SystemObjects,
     Option<SystemInput>,
 ) -> Result<SystemReturn, Error>;
 
@@ -107,16 +227,6 @@ macro_rules! apply_system {
     };
 }
 
-#[macro_export]
-macro_rules! filter_objects {
-    ($objs:ident, $filters:tt) => {
-        $objs
-            .into_iter()
-            .filter(|v| $filters.contains(&v.offset()))
-            .collect::<Vec<_>>()
-    };
-}
-
 impl SystemController {
     pub fn new(resource_pool: generated::ResourcePool, rng: SporeRng) -> Self {
         let mut controller = BTreeMap::new();
@@ -150,7 +260,16 @@ impl SystemController {
             (TriggerRecoverHp, triggered::recover_hp),
             (TriggerRecoverHpAttack, triggered::recover_hp_to_attack),
             (TriggerAttackUp, triggered::attack_up),
-            (TriggerAttackDown, triggered::attack_down)
+            (TriggerAttackDown, triggered::attack_down),
+            (TriggerPoison, triggered::poison),
+            (TriggerBurn, triggered::burn),
+            (TriggerBleed, triggered::bleed),
+            (TriggerThorns, triggered::thorns),
+            (TriggerReflect, triggered::reflect),
+            (TriggerStun, triggered::stun),
+            (TriggerSilence, triggered::silence),
+            (TriggerFreeze, triggered::freeze),
+            (InstantSummonEnemies, instant::summon_enemies)
         );
         Self {
             resource_pool,
@@ -159,12 +278,24 @@ impl SystemController {
         }
     }
 
+    // downstream crates are able to extend systems under ids that built-in systems don't occupy
+    #[cfg(feature = "custom_system")]
+    pub fn register_system(&mut self, id: u16, system: SystemCallback) -> Result<(), Error> {
+        if id < CUSTOM_SYSTEM_ID_START {
+            return Err(Error::SystemIdReserved);
+        }
+        let system_id = SystemId::Custom(id);
+        if self.controller.contains_key(&system_id) {
+            return Err(Error::SystemIdDuplicated);
+        }
+        self.controller.insert(system_id, system);
+        Ok(())
+    }
+
     pub fn system_call(
         &mut self,
         ctx: SystemContext,
-        caster: usize,
-        targets: Vec<usize>,
-        objects: &mut [&mut dyn CtxAdaptor],
+        objects: &mut SystemObjects,
         system_input: Option<SystemInput>,
     ) -> Result<SystemReturn, Error> {
         let system_trigger = self
@@ -175,8 +306,6 @@ impl SystemController {
             &self.resource_pool,
             &mut self.rng,
             ctx,
-            caster,
-            targets,
             objects,
             system_input,
         )
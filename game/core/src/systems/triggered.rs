@@ -1,17 +1,18 @@
 extern crate alloc;
+use alloc::boxed::Box;
 use alloc::{vec, vec::Vec};
 use spore_warriors_generated as generated;
 
+use crate::apply_system;
 use crate::battle::traits::FightLog;
-use crate::contexts::{ContextType, CtxAdaptor, SystemContext};
+use crate::contexts::{ContextType, CtxAdaptor, SystemContext, WarriorDeckContext};
 use crate::errors::Error;
 use crate::game::SporeRng;
 use crate::systems::applications::healing_apply;
-use crate::systems::{Command, SystemInput, SystemReturn};
+use crate::systems::{Command, SystemInput, SystemObjects, SystemReturn};
 use crate::wrappings::Value;
-use crate::{apply_system, filter_objects};
 
-use super::applications::attack_apply;
+use super::applications::{attack_apply, fixed_damage_apply};
 
 enum TriggerResult {
     BreakOut(SystemReturn),
@@ -23,27 +24,14 @@ fn run_trigger(
     objects: &mut Vec<&mut &mut dyn CtxAdaptor>,
     input: &Option<SystemInput>,
 ) -> Result<TriggerResult, Error> {
-    let Some(SystemInput::Trigger(trigger)) = &input else {
-        return Ok(TriggerResult::BreakOut(SystemReturn::Continue(vec![])));
-    };
     let Some(duration) = ctx.system.duration else {
         return Err(Error::BattleUnexpectedSystemDuration);
     };
     let mut logs = vec![];
-    if ctx.duration_counter > 0 {
-        if trigger == &duration.trigger {
-            ctx.duration_counter -= 1;
-            objects.iter_mut().for_each(|object| {
-                if ctx.duration_counter == 0 {
-                    object.remove_mounting_system(&ctx);
-                    logs.push(FightLog::RemoveSystem(object.offset(), ctx.clone()));
-                } else {
-                    object.update_mounting_system(&ctx);
-                    logs.push(FightLog::UpdateSystem(object.offset(), ctx.clone()));
-                }
-            });
+    if ctx.duration_counter == 0 {
+        if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
+            return Ok(TriggerResult::BreakOut(SystemReturn::Continue(vec![])));
         }
-    } else {
         assert!(duration.count > 0);
         ctx.duration_counter = duration.count;
         objects.iter_mut().for_each(|object| {
@@ -54,24 +42,89 @@ fn run_trigger(
             Command::AddLogs(logs),
         ])));
     }
+    let Some(SystemInput::Trigger(trigger)) = &input else {
+        return Ok(TriggerResult::BreakOut(SystemReturn::Continue(vec![])));
+    };
+    if trigger == &duration.trigger {
+        ctx.duration_counter -= 1;
+        objects.iter_mut().for_each(|object| {
+            if ctx.duration_counter == 0 {
+                object.remove_mounting_system(&ctx);
+                logs.push(FightLog::RemoveSystem(object.offset(), ctx.clone()));
+            } else {
+                object.update_mounting_system(&ctx);
+                logs.push(FightLog::UpdateSystem(object.offset(), ctx.clone()));
+            }
+        });
+    }
     Ok(TriggerResult::Continue(logs, ctx, trigger.clone()))
 }
 
+// systems reacting on their duration trigger, instead of their own logs which trigger them again
+fn is_duration_trigger(ctx: &SystemContext, trigger: &FightLog) -> bool {
+    ctx.system
+        .duration
+        .map(|duration| trigger == &duration.trigger)
+        .unwrap_or_default()
+}
+
+// targets at first casting, or the mounted object which is the caster in triggering
+fn mounting_objects<'a, 'b>(
+    objects: &'b mut SystemObjects<'a>,
+    input: &Option<SystemInput>,
+) -> Result<Vec<&'b mut &'a mut dyn CtxAdaptor>, Error> {
+    if let Some(SystemInput::Trigger(_)) = input {
+        Ok(vec![objects.caster()?])
+    } else {
+        Ok(objects.targets().iter_mut().collect())
+    }
+}
+
+// mount on targets at first, then hurt the mounted object every time its turn begins
+fn damage_over_time(
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+    log: fn(usize, u16) -> FightLog,
+) -> Result<SystemReturn, Error> {
+    let mut objects = mounting_objects(objects, &input)?;
+    let (mut logs, ctx, trigger) = match run_trigger(ctx, &mut objects, &input)? {
+        TriggerResult::BreakOut(result) => return Ok(result),
+        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
+    };
+    if !matches!(trigger, FightLog::PlayerTurn(_) | FightLog::EnemyTurn(_)) {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    }
+    if !is_duration_trigger(&ctx, &trigger) {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    }
+    let mut iter = ctx.system.args.iter();
+    let Some(Value(damage)) = iter.next() else {
+        return Err(Error::BattleUnexpectedSystemArgs);
+    };
+    let pierce = iter.next().map(|Value(v)| *v).unwrap_or_default();
+    for object in objects {
+        fixed_damage_apply(&mut logs, *damage, pierce, object, log)?;
+    }
+    Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
+}
+
 // recover hp after specific trigger triggered, maybe can last for some period
 pub fn recover_hp(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    _: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
-    let mut objects = filter_objects!(objects, targets);
-    let (mut logs, ctx) = match run_trigger(ctx, &mut objects, &input)? {
+    let mut objects = objects.targets().iter_mut().collect();
+    let (mut logs, ctx, trigger) = match run_trigger(ctx, &mut objects, &input)? {
         TriggerResult::BreakOut(result) => return Ok(result),
-        TriggerResult::Continue(logs, ctx, _) => (logs, ctx),
+        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
     };
+    if !is_duration_trigger(&ctx, &trigger) {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    }
     let mut iter = ctx.system.args.iter();
     apply_system!(logs, iter, input, objects, u16, healing_apply);
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
@@ -81,16 +134,24 @@ pub fn recover_hp(
 pub fn recover_hp_to_attack(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
-    ctx: SystemContext,
-    caster: usize,
-    targets: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    mut ctx: SystemContext,
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
-    let mut trigger_objects = filter_objects!(objects, { vec![caster] });
-    let (mut logs, mut ctx, trigger) = match run_trigger(ctx, &mut trigger_objects, &input)? {
+    let source = objects.caster_offset();
+    let (caster, objects) = objects.split()?;
+    // record the casting targets, which are the ones to damage whenever triggered later
+    if ctx.duration_counter == 0 {
+        ctx.register_3 = objects.iter().map(|v| v.offset()).collect();
+    }
+    let objects = objects
+        .into_iter()
+        .filter(|v| ctx.register_3.contains(&v.offset()))
+        .collect::<Vec<_>>();
+    let mut trigger_objects = vec![caster];
+    let (mut logs, trigger) = match run_trigger(ctx, &mut trigger_objects, &input)? {
         TriggerResult::BreakOut(result) => return Ok(result),
-        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
+        TriggerResult::Continue(logs, _, trigger) => (logs, trigger),
     };
     if let Some(SystemInput::Trigger(FightLog::GameOver)) = input {
         return Ok(SystemReturn::Continue(vec![]));
@@ -98,16 +159,8 @@ pub fn recover_hp_to_attack(
     let FightLog::SystemRecoverHp(_, hp) = trigger else {
         return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
     };
-    if ctx.register_3.is_empty() {
-        ctx.register_3 = targets;
-        trigger_objects.iter_mut().for_each(|object| {
-            object.update_mounting_system(&ctx);
-            logs.push(FightLog::UpdateSystem(object.offset(), ctx.clone()));
-        });
-    }
-    let objects = filter_objects!(objects, { &ctx.register_3 });
     for object in objects {
-        attack_apply(&mut logs, hp, object)?;
+        attack_apply(&mut logs, hp, source, object)?;
     }
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
@@ -117,16 +170,17 @@ pub fn attack_up(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    caster: usize,
-    _: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
-    let mut objects = filter_objects!(objects, { vec![caster] });
-    let (mut logs, ctx) = match run_trigger(ctx, &mut objects, &input)? {
+    let mut objects = vec![objects.caster()?];
+    let (mut logs, ctx, trigger) = match run_trigger(ctx, &mut objects, &input)? {
         TriggerResult::BreakOut(result) => return Ok(result),
-        TriggerResult::Continue(logs, ctx, _) => (logs, ctx),
+        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
     };
+    if !is_duration_trigger(&ctx, &trigger) {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    }
     let mut iter = ctx.system.args.iter();
     apply_system!(
         logs,
@@ -146,16 +200,17 @@ pub fn attack_down(
     _: &generated::ResourcePool,
     _: &mut SporeRng,
     ctx: SystemContext,
-    caster: usize,
-    _: Vec<usize>,
-    objects: &mut [&mut dyn CtxAdaptor],
+    objects: &mut SystemObjects,
     input: Option<SystemInput>,
 ) -> Result<SystemReturn, Error> {
-    let mut objects = filter_objects!(objects, { vec![caster] });
-    let (mut logs, ctx) = match run_trigger(ctx, &mut objects, &input)? {
+    let mut objects = vec![objects.caster()?];
+    let (mut logs, ctx, trigger) = match run_trigger(ctx, &mut objects, &input)? {
         TriggerResult::BreakOut(result) => return Ok(result),
-        TriggerResult::Continue(logs, ctx, _) => (logs, ctx),
+        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
     };
+    if !is_duration_trigger(&ctx, &trigger) {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    }
     let mut iter = ctx.system.args.iter();
     apply_system!(
         logs,
@@ -170,6 +225,186 @@ pub fn attack_down(
     Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
 }
 
+// poison the targets, which ticks with the configured turn trigger
+pub fn poison(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    damage_over_time(ctx, objects, input, FightLog::SystemPoisonDamage)
+}
+
+// burn the targets, which ticks with the configured turn trigger
+pub fn burn(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    damage_over_time(ctx, objects, input, FightLog::SystemBurnDamage)
+}
+
+// make the targets bleed, which ticks with the configured turn trigger
+pub fn bleed(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    damage_over_time(ctx, objects, input, FightLog::SystemBleedDamage)
+}
+
+// hurt back the attacker with the damage computed by `damage`, once the owner gets damaged
+fn counter_attack(
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+    damage: fn(u16, u16) -> u16,
+    log: fn(usize, u16) -> FightLog,
+) -> Result<SystemReturn, Error> {
+    let owner = objects.caster_offset();
+    // only the damage on owner from others takes a charge, the rest never reaches the trigger
+    if let Some(SystemInput::Trigger(trigger)) = &input {
+        let hit = matches!(
+            trigger,
+            FightLog::SystemDamage(source, target, _) if *target == owner && *source != owner
+        );
+        if ctx.duration_counter > 0 && !hit {
+            return Ok(SystemReturn::Continue(vec![]));
+        }
+    }
+    let mut trigger_objects = vec![objects.caster()?];
+    let (mut logs, ctx, trigger) = match run_trigger(ctx, &mut trigger_objects, &input)? {
+        TriggerResult::BreakOut(result) => return Ok(result),
+        TriggerResult::Continue(logs, ctx, trigger) => (logs, ctx, trigger),
+    };
+    let FightLog::SystemDamage(_, _, taken) = trigger else {
+        return Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]));
+    };
+    let mut iter = ctx.system.args.iter();
+    let Some(Value(value)) = iter.next() else {
+        return Err(Error::BattleUnexpectedSystemArgs);
+    };
+    let pierce = iter.next().map(|Value(v)| *v).unwrap_or_default();
+    let attacker = objects.source()?;
+    fixed_damage_apply(&mut logs, damage(*value, taken), pierce, attacker, log)?;
+    Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
+}
+
+// fixed damage back to the attacker who damaged the owner
+pub fn thorns(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    counter_attack(
+        ctx,
+        objects,
+        input,
+        |value, _| value,
+        FightLog::SystemThornsDamage,
+    )
+}
+
+// percentage of the damage taken by owner back to the attacker
+pub fn reflect(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    counter_attack(
+        ctx,
+        objects,
+        input,
+        |percent, taken| (taken as u32 * percent as u32 / 100) as u16,
+        FightLog::SystemReflectDamage,
+    )
+}
+
+// mount control effect on targets, which only counts down by its trigger
+fn control_effect(
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    let mut objects = mounting_objects(objects, &input)?;
+    match run_trigger(ctx, &mut objects, &input)? {
+        TriggerResult::BreakOut(result) => Ok(result),
+        TriggerResult::Continue(logs, _, _) => {
+            Ok(SystemReturn::Continue(vec![Command::AddLogs(logs)]))
+        }
+    }
+}
+
+// stunned enemy skips its actions
+pub fn stun(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    control_effect(ctx, objects, input)
+}
+
+// silenced player cannot use the special card
+pub fn silence(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    control_effect(ctx, objects, input)
+}
+
+// frozen card cannot be played, the player targeted by freezing holds it until the next
+// draw, then the drawn card gets frozen
+pub fn freeze(
+    _: &generated::ResourcePool,
+    _: &mut SporeRng,
+    ctx: SystemContext,
+    objects: &mut SystemObjects,
+    input: Option<SystemInput>,
+) -> Result<SystemReturn, Error> {
+    let Some(SystemInput::Trigger(trigger)) = &input else {
+        return control_effect(ctx, objects, input);
+    };
+    let holder = objects.caster()?;
+    if holder.context_type() != ContextType::Warrior {
+        return control_effect(ctx, objects, input);
+    }
+    let FightLog::Draw(card_offset) = *trigger else {
+        return Ok(SystemReturn::Continue(vec![]));
+    };
+    // the same freezing may be triggered by several draws, only the first one takes it
+    if !holder.remove_mounting_system(&ctx) {
+        return Ok(SystemReturn::Continue(vec![]));
+    }
+    let holder_offset = holder.offset();
+    let operator = move |player_deck: &mut WarriorDeckContext| {
+        let mut logs = vec![FightLog::RemoveSystem(holder_offset, ctx.clone())];
+        if let Some(card) = player_deck
+            .hand_deck
+            .iter_mut()
+            .find(|v| v.offset() == card_offset)
+        {
+            card.add_mounting_system(&ctx);
+            logs.push(FightLog::AddSystem(card_offset, ctx.clone()));
+        }
+        logs
+    };
+    Ok(SystemReturn::RequireDeckChange(Box::new(operator)))
+}
+
 // decrease card power cost when the other one used with the same card class
 // pub fn card_cost_light_up(
 //     _: &generated::ResourcePool,
@@ -1,5 +1,5 @@
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::cmp::max;
 use core::sync::atomic::AtomicUsize;
 use rand::RngCore;
@@ -11,7 +11,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::Error;
 
-static OFFSET: AtomicUsize = AtomicUsize::new(10);
+// offsets below are reserved for the player and enemies, cards take the rest
+pub const CARD_OFFSET_START: usize = 10;
+
+static OFFSET: AtomicUsize = AtomicUsize::new(CARD_OFFSET_START);
 
 macro_rules! randomized_pool {
     ($val:ident.$meth:ident(), $pool:ident.$pmeth:ident(), $retn:ty, $rng:ident) => {{
@@ -63,6 +66,34 @@ pub fn randomized_selection<T: IntoIterator>(
         .collect::<Vec<_>>()
 }
 
+// pick without replacement, the chance of each element is proportional to its weight
+pub fn randomized_weighted_selection<T>(
+    mut pool: Vec<T>,
+    count: u8,
+    weight: impl Fn(&T) -> u32,
+    rng: &mut impl RngCore,
+) -> Vec<T> {
+    let mut weights = pool.iter().map(&weight).collect::<Vec<_>>();
+    (0..count)
+        .filter_map(|_| {
+            let total = weights.iter().map(|v| *v as u64).sum::<u64>();
+            if total == 0 {
+                return None;
+            }
+            let mut sample = rng.next_u32() as u64 % total;
+            let index = weights.iter().position(|v| {
+                if sample < *v as u64 {
+                    return true;
+                }
+                sample -= *v as u64;
+                false
+            })?;
+            weights.remove(index);
+            Some(pool.remove(index))
+        })
+        .collect::<Vec<_>>()
+}
+
 #[cfg_attr(feature = "debug", derive(Debug, PartialEq))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
 #[derive(Clone, RlpEncodable, RlpDecodable)]
@@ -80,12 +111,16 @@ impl Value {
 #[cfg_attr(feature = "debug", derive(Debug, PartialEq))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy)]
+#[repr(u8)]
 pub enum RequireTarget {
-    Owner,
-    Opponent,
-    RandomOpponent,
-    AllOpponents,
-    AllCharactors,
+    Owner = 0,
+    Opponent = 1,
+    RandomOpponent = 3,
+    AllOpponents = 4,
+    AllCharactors = 5,
+    RandomAlly = 6,
+    AllAllies = 7,
+    LowestHpAlly = 8,
 }
 
 impl TryFrom<u8> for RequireTarget {
@@ -98,6 +133,9 @@ impl TryFrom<u8> for RequireTarget {
             3 => Ok(Self::RandomOpponent),
             4 => Ok(Self::AllOpponents),
             5 => Ok(Self::AllCharactors),
+            6 => Ok(Self::RandomAlly),
+            7 => Ok(Self::AllAllies),
+            8 => Ok(Self::LowestHpAlly),
             _ => Err(Error::ResourceBrokenTargetPosition),
         }
     }
@@ -105,7 +143,7 @@ impl TryFrom<u8> for RequireTarget {
 
 impl rlp::Encodable for RequireTarget {
     fn rlp_append(&self, s: &mut rlp::RlpStream) {
-        let target = self.clone() as u8;
+        let target = *self as u8;
         s.begin_list(1).append(&target);
     }
 }
@@ -154,11 +192,66 @@ pub enum SystemId {
     TriggerRecoverHpAttack,
     TriggerAttackUp,
     TriggerAttackDown,
+    TriggerPoison,
+    TriggerBurn,
+    TriggerBleed,
+    TriggerThorns,
+    TriggerReflect,
+    TriggerStun,
+    TriggerSilence,
+    TriggerFreeze,
+    InstantSummonEnemies,
+    #[cfg(feature = "custom_system")]
+    Custom(u16),
 }
 
+// system ids below this value are reserved for built-in systems
+pub const CUSTOM_SYSTEM_ID_START: u16 = 1000;
+
 impl From<SystemId> for u16 {
     fn from(value: SystemId) -> Self {
-        value as u16
+        match value {
+            SystemId::InstantDamage => 0,
+            SystemId::InstantMultipleDamage => 1,
+            SystemId::InstantArmorUp => 2,
+            SystemId::InstantArmorDown => 3,
+            SystemId::InstantShieldUp => 4,
+            SystemId::InstantShieldDown => 5,
+            SystemId::InstantHealing => 6,
+            SystemId::InstantDrawCountUp => 7,
+            SystemId::InstantDrawCountDown => 8,
+            SystemId::InstantAttackUp => 9,
+            SystemId::InstantAttackDown => 10,
+            SystemId::InstantDefenseUp => 11,
+            SystemId::InstantDefenseDown => 12,
+            SystemId::InstantAttackWeakUp => 13,
+            SystemId::InstantAttackWeakDown => 14,
+            SystemId::InstantDefenseWeakUp => 15,
+            SystemId::InstantDefenseWeakDown => 16,
+            SystemId::InstantMaxHpUp => 17,
+            SystemId::InstantMaxHpDown => 18,
+            SystemId::InstantDrawCards => 19,
+            SystemId::InstantDrawSelectCards => 20,
+            SystemId::InstantDiscardSelectCards => 21,
+            SystemId::InstantDiscardCards => 22,
+            SystemId::InstantPowerCostDown => 23,
+            SystemId::InstantChangePowerCost => 24,
+            SystemId::TriggerRecoverHp => 25,
+            SystemId::TriggerRecoverHpAttack => 26,
+            SystemId::TriggerAttackUp => 27,
+            SystemId::TriggerAttackDown => 28,
+            SystemId::TriggerPoison => 29,
+            SystemId::TriggerBurn => 30,
+            SystemId::TriggerBleed => 31,
+            SystemId::TriggerThorns => 32,
+            SystemId::TriggerReflect => 33,
+            SystemId::TriggerStun => 34,
+            SystemId::TriggerSilence => 35,
+            SystemId::TriggerFreeze => 36,
+            SystemId::InstantSummonEnemies => 37,
+            #[cfg(feature = "custom_system")]
+            SystemId::Custom(id) => id,
+        }
     }
 }
 
@@ -196,6 +289,17 @@ impl TryFrom<u16> for SystemId {
             26 => Ok(Self::TriggerRecoverHpAttack),
             27 => Ok(Self::TriggerAttackUp),
             28 => Ok(Self::TriggerAttackDown),
+            29 => Ok(Self::TriggerPoison),
+            30 => Ok(Self::TriggerBurn),
+            31 => Ok(Self::TriggerBleed),
+            32 => Ok(Self::TriggerThorns),
+            33 => Ok(Self::TriggerReflect),
+            34 => Ok(Self::TriggerStun),
+            35 => Ok(Self::TriggerSilence),
+            36 => Ok(Self::TriggerFreeze),
+            37 => Ok(Self::InstantSummonEnemies),
+            #[cfg(feature = "custom_system")]
+            id if id >= CUSTOM_SYSTEM_ID_START => Ok(Self::Custom(id)),
             _ => Err(Error::ResourceBrokenSystemId),
         }
     }
@@ -203,7 +307,7 @@ impl TryFrom<u16> for SystemId {
 
 impl rlp::Encodable for SystemId {
     fn rlp_append(&self, s: &mut rlp::RlpStream) {
-        let id = self.clone() as u16;
+        let id = u16::from(*self);
         s.begin_list(1).append(&id);
     }
 }
@@ -312,6 +416,46 @@ impl rlp::Decodable for ItemClass {
     }
 }
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum EquipmentSlot {
+    Weapon,
+    Armor,
+    Accessory,
+}
+
+impl TryFrom<u8> for EquipmentSlot {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Weapon),
+            1 => Ok(Self::Armor),
+            2 => Ok(Self::Accessory),
+            _ => Err(Error::ResourceBrokenEquipmentSlot),
+        }
+    }
+}
+
+impl rlp::Encodable for EquipmentSlot {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        let slot = *self as u8;
+        s.begin_list(1).append(&slot);
+    }
+}
+
+impl rlp::Decodable for EquipmentSlot {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let value: u8 = rlp.val_at(0)?;
+        let slot: Self = value
+            .try_into()
+            .map_err(|_| rlp::DecoderError::Custom("invalid EquipmentSlot"))?;
+        Ok(slot)
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug, PartialEq))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
 #[derive(Clone, RlpDecodable, RlpEncodable)]
@@ -322,6 +466,7 @@ pub struct Item {
     pub weight: u8,
     pub price: u16,
     pub system_pool: Vec<System>,
+    pub slot: EquipmentSlot,
 }
 
 impl Item {
@@ -343,13 +488,24 @@ impl Item {
             weight: randomized_byte(value.random_weight(), rng),
             price: randomized_number(value.price(), rng),
             system_pool,
+            slot: u8::from(value.slot()).try_into()?,
         })
     }
+
+    pub fn sell_price(&self) -> u16 {
+        self.price / 2
+    }
+
+    // higher quality drops less, `rarity` is a percent modifier lifting it back
+    pub fn drop_weight(&self, rarity: u8) -> u32 {
+        let quality = self.quality as u32;
+        max(self.weight, 1) as u32 * (100 + rarity as u32 * quality) / (quality + 1)
+    }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
-#[derive(Clone)]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
 pub struct Loot {
     pub gold: u16,
     pub score: u16,
@@ -363,30 +519,53 @@ impl Loot {
         resource_pool: &generated::ResourcePool,
         value: generated::Loot,
         rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        Self::rarity_randomized(resource_pool, value, 0, rng)
+    }
+
+    pub fn rarity_randomized(
+        resource_pool: &generated::ResourcePool,
+        value: generated::Loot,
+        rarity: u8,
+        rng: &mut impl RngCore,
     ) -> Result<Self, Error> {
         fn package_unpack(
             resource_pool: &generated::ResourcePool,
             package: Option<generated::Package>,
+            rarity: u8,
             rng: &mut impl RngCore,
         ) -> Result<Vec<Item>, Error> {
             let Some(package) = package else {
                 return Ok(Default::default());
             };
-            randomized_pool!(package.item_pool(), resource_pool.item_pool(), Item, rng)
+            let items =
+                randomized_pool!(package.item_pool(), resource_pool.item_pool(), Item, rng)?;
+            let size = package.size().into();
+            Ok(randomized_weighted_selection(
+                items,
+                size,
+                |item| item.drop_weight(rarity),
+                rng,
+            ))
         }
         Ok(Self {
             gold: randomized_number(value.gold(), rng),
             score: randomized_number(value.score(), rng),
-            card_pool: package_unpack(resource_pool, Some(value.card_pool()), rng)?,
-            props_pool: package_unpack(resource_pool, value.props_pool().to_opt(), rng)?,
-            equipment_pool: package_unpack(resource_pool, value.equipment_pool().to_opt(), rng)?,
+            card_pool: package_unpack(resource_pool, Some(value.card_pool()), rarity, rng)?,
+            props_pool: package_unpack(resource_pool, value.props_pool().to_opt(), rarity, rng)?,
+            equipment_pool: package_unpack(
+                resource_pool,
+                value.equipment_pool().to_opt(),
+                rarity,
+                rng,
+            )?,
         })
     }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
-#[derive(Clone)]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
 pub struct Action {
     pub random_select: bool,
     pub system_pool: Vec<System>,
@@ -414,9 +593,91 @@ impl Action {
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
 #[derive(Clone)]
+pub enum ActionCondition {
+    OwnHpBelow(u8),
+    PlayerShieldAbove(u16),
+    RoundMultipleOf(u8),
+}
+
+impl rlp::Encodable for ActionCondition {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        let (condition_type, value) = match self {
+            Self::OwnHpBelow(percent) => (0u8, *percent as u16),
+            Self::PlayerShieldAbove(shield) => (1u8, *shield),
+            Self::RoundMultipleOf(n) => (2u8, *n as u16),
+        };
+        s.begin_list(2).append(&condition_type).append(&value);
+    }
+}
+
+impl rlp::Decodable for ActionCondition {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let condition_type: u8 = rlp.val_at(0)?;
+        let value: u16 = rlp.val_at(1)?;
+        match condition_type {
+            0 => Ok(Self::OwnHpBelow(value as u8)),
+            1 => Ok(Self::PlayerShieldAbove(value)),
+            2 => Ok(Self::RoundMultipleOf(value as u8)),
+            _ => Err(rlp::DecoderError::Custom("invalid ActionCondition")),
+        }
+    }
+}
+
+impl TryFrom<generated::ActionCondition> for ActionCondition {
+    type Error = Error;
+
+    fn try_from(value: generated::ActionCondition) -> Result<Self, Self::Error> {
+        let number: u16 = value.value().into();
+        match u8::from(value.condition_type()) {
+            0 if number <= 100 => Ok(Self::OwnHpBelow(number as u8)),
+            1 => Ok(Self::PlayerShieldAbove(number)),
+            2 if number > 0 && number <= u8::MAX as u16 => Ok(Self::RoundMultipleOf(number as u8)),
+            _ => Err(Error::ResourceBrokenActionCondition),
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
+pub struct ActionRule {
+    pub action: Action,
+    pub weight: u8,
+    pub cooldown: u8,
+    pub conditions: Vec<ActionCondition>,
+}
+
+impl ActionRule {
+    pub fn randomized(
+        resource_pool: &generated::ResourcePool,
+        value: generated::ActionRule,
+        rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        let action = resource_pool
+            .action_pool()
+            .into_iter()
+            .find(|v| v.id().raw_data() == value.action().raw_data())
+            .ok_or(Error::ResourceBrokenActionPool)?;
+        Ok(Self {
+            action: Action::randomized(resource_pool, action, rng)?,
+            weight: value.weight().into(),
+            cooldown: value.cooldown().into(),
+            conditions: value
+                .conditions()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
 pub struct ActionStrategy {
     pub random_select: bool,
     pub actions: Vec<Action>,
+    pub rules: Vec<ActionRule>,
 }
 
 impl ActionStrategy {
@@ -431,19 +692,101 @@ impl ActionStrategy {
             Action,
             rng
         )?;
+        let rules = value
+            .rules()
+            .into_iter()
+            .map(|rule| ActionRule::randomized(resource_pool, rule, rng))
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(Self {
             random_select: u8::from(value.random()) == 1u8,
             actions,
+            rules,
         })
     }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
-#[derive(Clone)]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
+pub struct EnemyPhase {
+    pub hp_percent: u8,
+    pub strategy: ActionStrategy,
+}
+
+impl EnemyPhase {
+    pub fn randomized(
+        resource_pool: &generated::ResourcePool,
+        value: generated::EnemyPhase,
+        rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        let hp_percent: u8 = value.hp_percent().into();
+        if hp_percent > 100 {
+            return Err(Error::ResourceBrokenHpPercent);
+        }
+        Ok(Self {
+            hp_percent,
+            strategy: ActionStrategy::randomized(resource_pool, value.action_strategy(), rng)?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum EnemyRank {
+    Normal,
+    Elite,
+    Boss,
+}
+
+impl TryFrom<u8> for EnemyRank {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Normal),
+            1 => Ok(Self::Elite),
+            2 => Ok(Self::Boss),
+            _ => Err(Error::ResourceBrokenEnemyRank),
+        }
+    }
+}
+
+impl rlp::Encodable for EnemyRank {
+    fn rlp_append(&self, s: &mut rlp::RlpStream) {
+        let rank = *self as u8;
+        s.begin_list(1).append(&rank);
+    }
+}
+
+impl rlp::Decodable for EnemyRank {
+    fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let value: u8 = rlp.val_at(0)?;
+        let rank: Self = value
+            .try_into()
+            .map_err(|_| rlp::DecoderError::Custom("invalid EnemyRank"))?;
+        Ok(rank)
+    }
+}
+
+impl EnemyRank {
+    // rarity modifier of the loot dropped by enemies in this rank
+    pub fn loot_rarity(&self) -> u8 {
+        match self {
+            Self::Normal => 0,
+            Self::Elite => 50,
+            Self::Boss => 100,
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, RlpEncodable, RlpDecodable)]
 pub struct Enemy {
     pub id: u16,
-    pub rank: u8,
+    pub rank: EnemyRank,
     pub hp: u16,
     pub armor: u8,
     pub shield: u8,
@@ -453,6 +796,7 @@ pub struct Enemy {
     pub defense_weak: u8,
     pub rewards: Vec<Loot>,
     pub strategy: ActionStrategy,
+    pub phases: Vec<EnemyPhase>,
 }
 
 impl Enemy {
@@ -461,13 +805,31 @@ impl Enemy {
         value: generated::Enemy,
         rng: &mut impl RngCore,
     ) -> Result<Self, Error> {
-        let rewards = randomized_pool!(value.loot_pool(), resource_pool.loot_pool(), Loot, rng)?;
+        let rank: EnemyRank = u8::from(value.rank()).try_into()?;
+        let loot_pool: Vec<u16> = value.loot_pool().into();
+        let rewards = resource_pool
+            .loot_pool()
+            .into_iter()
+            .filter(|v| loot_pool.contains(&v.id().into()))
+            .map(|v| Loot::rarity_randomized(resource_pool, v, rank.loot_rarity(), rng))
+            .collect::<Result<Vec<_>, _>>()?;
         let strategy = ActionStrategy::randomized(resource_pool, value.action_strategy(), rng)?;
+        let phases = value
+            .phases()
+            .into_iter()
+            .map(|phase| EnemyPhase::randomized(resource_pool, phase, rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        if phases
+            .windows(2)
+            .any(|pair| pair[0].hp_percent <= pair[1].hp_percent)
+        {
+            return Err(Error::ResourceBrokenEnemyPhase);
+        }
         Ok(Self {
             id: value.id().into(),
-            rank: value.rank().into(),
+            rank,
             hp: value.hp().into(),
-            armor: value.rank().into(),
+            armor: value.armor().into(),
             shield: value.shield().into(),
             attack: value.attack().into(),
             attack_weak: value.attack_weak().into(),
@@ -475,6 +837,7 @@ impl Enemy {
             defense_weak: value.defense_weak().into(),
             rewards,
             strategy,
+            phases,
         })
     }
 }
@@ -563,6 +926,12 @@ impl SizedPoint {
     }
 }
 
+// card keywords, stored as bit flags in `Card::keywords`
+pub const CARD_KEYWORD_EXHAUST: u8 = 0b0001;
+pub const CARD_KEYWORD_RETAIN: u8 = 0b0010;
+pub const CARD_KEYWORD_INNATE: u8 = 0b0100;
+pub const CARD_KEYWORD_ETHEREAL: u8 = 0b1000;
+
 #[cfg_attr(feature = "debug", derive(Debug, PartialEq))]
 #[cfg_attr(feature = "json_serde", derive(Serialize, Deserialize))]
 #[derive(Clone, RlpEncodable, RlpDecodable)]
@@ -573,9 +942,15 @@ pub struct Card {
     pub power_cost: u8,
     pub price: u16,
     pub system_pool: Vec<System>,
+    pub keywords: u8,
+    pub upgrade: Option<u16>,
 }
 
 impl Card {
+    pub fn has_keyword(&self, keyword: u8) -> bool {
+        self.keywords & keyword == keyword
+    }
+
     pub fn randomized(
         resource_pool: &generated::ResourcePool,
         value: generated::Card,
@@ -607,8 +982,27 @@ impl Card {
             power_cost: value.cost().into(),
             price: randomized_number(value.price(), rng),
             system_pool,
+            keywords: value.keywords().into(),
+            upgrade: value.upgrade().to_opt().map(Into::into),
         })
     }
+
+    // upgraded card keeps the offset of the original one
+    pub fn upgraded(
+        &self,
+        resource_pool: &generated::ResourcePool,
+        rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        let upgrade = self.upgrade.ok_or(Error::SceneCardNotUpgradable)?;
+        let value = resource_pool
+            .card_pool()
+            .into_iter()
+            .find(|v| u16::from(v.id()) == upgrade)
+            .ok_or(Error::ResourceBrokenCardPool)?;
+        let mut card = Self::randomized(resource_pool, value, rng)?;
+        card.offset = self.offset;
+        Ok(card)
+    }
 }
 
 #[cfg_attr(feature = "debug", derive(Debug, PartialEq))]
@@ -683,13 +1077,17 @@ impl Warrior {
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[cfg_attr(feature = "json_serde", derive(Serialize))]
 pub enum Node {
-    Enemy(Vec<Enemy>),
+    Enemy(Vec<Vec<Enemy>>),
+    EliteEnemy(Vec<Enemy>),
+    BossEnemy(Vec<Enemy>),
     TreasureChest(Vec<Item>, u8),
     RecoverPoint(u8),
     ItemMerchant(Vec<Item>),
     CardMerchant(Vec<Card>),
     Unknown(Vec<System>),
     Campsite(System),
+    CardShrine(u16, u16),
+    CardAltar(u16, u16, Vec<Card>),
     Barrier,
     StartingPoint,
     TargetingPoint,
@@ -707,6 +1105,7 @@ impl LevelNode {
     pub fn randomized(
         resource_pool: &generated::ResourcePool,
         value: generated::LevelNode,
+        rarity: u8,
         rng: &mut impl RngCore,
     ) -> Result<Self, Error> {
         Ok(Self {
@@ -720,9 +1119,56 @@ impl LevelNode {
                         Enemy,
                         rng
                     )?;
+                    let mut waves = vec![randomized_selection(
+                        enemies.len(),
+                        enemies,
+                        value.count().into(),
+                        rng,
+                    )];
+                    // the following waves enter the battle one by one
+                    for wave in value.waves().into_iter() {
+                        let enemies = randomized_pool!(
+                            wave.enemy_pool(),
+                            resource_pool.enemy_pool(),
+                            Enemy,
+                            rng
+                        )?;
+                        waves.push(randomized_selection(
+                            enemies.len(),
+                            enemies,
+                            wave.count().into(),
+                            rng,
+                        ));
+                    }
+                    Node::Enemy(waves)
+                }
+                generated::NodeInstanceUnion::NodeEliteEnemy(value) => {
+                    let enemies = randomized_pool!(
+                        value.enemy_pool(),
+                        resource_pool.enemy_pool(),
+                        Enemy,
+                        rng
+                    )?;
+                    if enemies.iter().any(|v| v.rank != EnemyRank::Elite) {
+                        return Err(Error::ResourceBrokenEnemyRank);
+                    }
+                    let randomized_enemies =
+                        randomized_selection(enemies.len(), enemies, value.count().into(), rng);
+                    Node::EliteEnemy(randomized_enemies)
+                }
+                generated::NodeInstanceUnion::NodeBossEnemy(value) => {
+                    let enemies = randomized_pool!(
+                        value.enemy_pool(),
+                        resource_pool.enemy_pool(),
+                        Enemy,
+                        rng
+                    )?;
+                    if enemies.iter().any(|v| v.rank != EnemyRank::Boss) {
+                        return Err(Error::ResourceBrokenEnemyRank);
+                    }
                     let randomized_enemies =
                         randomized_selection(enemies.len(), enemies, value.count().into(), rng);
-                    Node::Enemy(randomized_enemies)
+                    Node::BossEnemy(randomized_enemies)
                 }
                 generated::NodeInstanceUnion::NodeRecoverPoint(value) => {
                     let percent: u8 = value.hp_percent().into();
@@ -734,8 +1180,12 @@ impl LevelNode {
                 generated::NodeInstanceUnion::NodeItemMerchant(value) => {
                     let goods =
                         randomized_pool!(value.item_pool(), resource_pool.item_pool(), Item, rng)?;
-                    let randomized_goods =
-                        randomized_selection(goods.len(), goods, value.count().into(), rng);
+                    let randomized_goods = randomized_weighted_selection(
+                        goods,
+                        value.count().into(),
+                        |item| item.drop_weight(rarity),
+                        rng,
+                    );
                     Node::ItemMerchant(randomized_goods)
                 }
                 generated::NodeInstanceUnion::NodeCardMerchant(value) => {
@@ -767,10 +1217,30 @@ impl LevelNode {
                 generated::NodeInstanceUnion::NodeTreasureChest(value) => {
                     let items =
                         randomized_pool!(value.item_pool(), resource_pool.item_pool(), Item, rng)?;
-                    let randomized_items =
-                        randomized_selection(items.len(), items, value.count().into(), rng);
+                    let randomized_items = randomized_weighted_selection(
+                        items,
+                        value.count().into(),
+                        |item| item.drop_weight(rarity),
+                        rng,
+                    );
                     Node::TreasureChest(randomized_items, value.pick().into())
                 }
+                generated::NodeInstanceUnion::NodeCardShrine(value) => Node::CardShrine(
+                    randomized_number(value.price(), rng),
+                    value.price_increase().into(),
+                ),
+                generated::NodeInstanceUnion::NodeCardAltar(value) => {
+                    let cards =
+                        randomized_pool!(value.card_pool(), resource_pool.card_pool(), Card, rng)?;
+                    if cards.is_empty() {
+                        return Err(Error::ResourceBrokenCardPool);
+                    }
+                    Node::CardAltar(
+                        randomized_number(value.price(), rng),
+                        value.price_increase().into(),
+                        cards,
+                    )
+                }
                 generated::NodeInstanceUnion::NodeBarrier(_) => Node::Barrier,
                 generated::NodeInstanceUnion::NodeStartingPoint(_) => Node::StartingPoint,
                 generated::NodeInstanceUnion::NodeTargetingPoint(_) => Node::TargetingPoint,
@@ -781,9 +1251,10 @@ impl LevelNode {
     pub fn fix_randomized(
         resource_pool: &generated::ResourcePool,
         value: generated::FixedLevelNode,
+        rarity: u8,
         rng: &mut impl RngCore,
     ) -> Result<Self, Error> {
-        let mut node = LevelNode::randomized(resource_pool, value.node(), rng)?;
+        let mut node = LevelNode::randomized(resource_pool, value.node(), rarity, rng)?;
         node.point = node
             .point
             .shift(value.point().x().into(), value.point().y().into());
@@ -801,8 +1272,10 @@ impl LevelPartition {
     pub fn randomized(
         resource_pool: &generated::ResourcePool,
         value: generated::ScenePartition,
+        rarity: u8,
         rng: &mut impl RngCore,
     ) -> Result<Self, Error> {
+        let rarity = rarity.saturating_add(value.rarity().into());
         let start: SizedPoint = value.start_point().into();
         let (x_diff, y_diff) = {
             let end: SizedPoint = value.end_point().into();
@@ -824,7 +1297,7 @@ impl LevelPartition {
         let nodes = value
             .node_pool()
             .into_iter()
-            .map(|node| LevelNode::randomized(resource_pool, node, rng))
+            .map(|node| LevelNode::randomized(resource_pool, node, rarity, rng))
             .collect::<Result<Vec<_>, _>>()?;
         let randomized_nodes = randomized_selection(nodes.len(), nodes, sample_count, rng)
             .into_iter()
@@ -862,6 +1335,44 @@ pub struct Potion {
 }
 
 impl Potion {
+    // brew a single potion from the alchemy template, whose cards and items are picked by size
+    pub fn brewed(
+        resource_pool: &generated::ResourcePool,
+        value: generated::PotionAlchemy,
+        rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        let deck_status =
+            randomized_pool!(value.deck_status(), resource_pool.card_pool(), Card, rng)?;
+        let package_status =
+            randomized_pool!(value.package_status(), resource_pool.item_pool(), Item, rng)?;
+        Ok(Self {
+            count: 1,
+            hp: randomized_byte(value.hp(), rng),
+            gold: randomized_byte(value.gold(), rng),
+            power: randomized_byte(value.power(), rng),
+            motion: randomized_byte(value.motion(), rng),
+            view_range: randomized_byte(value.view_range(), rng),
+            armor: randomized_byte(value.armor(), rng),
+            shield: randomized_byte(value.shield(), rng),
+            attack: randomized_byte(value.attack(), rng),
+            defense: randomized_byte(value.defense(), rng),
+            physique: randomized_byte(value.physique(), rng),
+            draw_count: randomized_byte(value.draw_count(), rng),
+            deck_status: randomized_selection(
+                deck_status.len(),
+                deck_status,
+                value.deck_size().into(),
+                rng,
+            ),
+            package_status: randomized_selection(
+                package_status.len(),
+                package_status,
+                value.package_size().into(),
+                rng,
+            ),
+        })
+    }
+
     pub fn randomized(
         resource_pool: &generated::ResourcePool,
         value: generated::Potion,
@@ -889,3 +1400,95 @@ impl Potion {
         })
     }
 }
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum ModifierClass {
+    EnemyHpPercentUp,
+    RandomPotion,
+    NoMerchant,
+}
+
+impl TryFrom<u8> for ModifierClass {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::EnemyHpPercentUp),
+            1 => Ok(Self::RandomPotion),
+            2 => Ok(Self::NoMerchant),
+            _ => Err(Error::ResourceBrokenModifierClass),
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(Clone)]
+pub struct ChallengeModifier {
+    pub id: u16,
+    pub class: ModifierClass,
+    pub value: u16,
+}
+
+impl TryFrom<generated::ChallengeModifier> for ChallengeModifier {
+    type Error = Error;
+
+    fn try_from(value: generated::ChallengeModifier) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id().into(),
+            class: u8::from(value.class()).try_into()?,
+            value: value.value().into(),
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum DifficultyClass {
+    EnemyHpPercentUp,
+    EnemyAttackUp,
+    MerchantPricePercentUp,
+    RecoverPercentDown,
+    CurseCard,
+}
+
+impl TryFrom<u8> for DifficultyClass {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::EnemyHpPercentUp),
+            1 => Ok(Self::EnemyAttackUp),
+            2 => Ok(Self::MerchantPricePercentUp),
+            3 => Ok(Self::RecoverPercentDown),
+            4 => Ok(Self::CurseCard),
+            _ => Err(Error::ResourceBrokenDifficultyClass),
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "json_serde", derive(Serialize))]
+#[derive(Clone)]
+pub struct DifficultyRule {
+    pub level: u8,
+    pub class: DifficultyClass,
+    pub value: u16,
+}
+
+impl TryFrom<generated::DifficultyRule> for DifficultyRule {
+    type Error = Error;
+
+    fn try_from(value: generated::DifficultyRule) -> Result<Self, Self::Error> {
+        Ok(Self {
+            level: value.level().into(),
+            class: u8::from(value.class()).try_into()?,
+            value: value.value().into(),
+        })
+    }
+}
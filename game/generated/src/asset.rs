@@ -1261,6 +1261,8 @@ impl ::core::fmt::Display for ResourcePool {
         write!(f, ", {}: {}", "warrior_pool", self.warrior_pool())?;
         write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
         write!(f, ", {}: {}", "client_assets", self.client_assets())?;
+        write!(f, ", {}: {}", "modifier_pool", self.modifier_pool())?;
+        write!(f, ", {}: {}", "difficulty_rules", self.difficulty_rules())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1275,12 +1277,13 @@ impl ::core::default::Default for ResourcePool {
     }
 }
 impl ResourcePool {
-    const DEFAULT_VALUE: [u8; 84] = [
-        84, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0,
-        68, 0, 0, 0, 72, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4,
-        0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 100] = [
+        100, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 72, 0, 0, 0,
+        76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 96, 0, 0, 0, 4, 0, 0, 0,
+        4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0,
+        0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 12;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1354,11 +1357,23 @@ impl ResourcePool {
     pub fn client_assets(&self) -> AssetVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        AssetVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn modifier_pool(&self) -> ChallengeModifierVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        ChallengeModifierVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn difficulty_rules(&self) -> DifficultyRuleVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            AssetVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            DifficultyRuleVec::new_unchecked(self.0.slice(start..end))
         } else {
-            AssetVec::new_unchecked(self.0.slice(start..))
+            DifficultyRuleVec::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> ResourcePoolReader<'r> {
@@ -1398,6 +1413,8 @@ impl molecule::prelude::Entity for ResourcePool {
             .warrior_pool(self.warrior_pool())
             .enemy_pool(self.enemy_pool())
             .client_assets(self.client_assets())
+            .modifier_pool(self.modifier_pool())
+            .difficulty_rules(self.difficulty_rules())
     }
 }
 #[derive(Clone, Copy)]
@@ -1429,6 +1446,8 @@ impl<'r> ::core::fmt::Display for ResourcePoolReader<'r> {
         write!(f, ", {}: {}", "warrior_pool", self.warrior_pool())?;
         write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
         write!(f, ", {}: {}", "client_assets", self.client_assets())?;
+        write!(f, ", {}: {}", "modifier_pool", self.modifier_pool())?;
+        write!(f, ", {}: {}", "difficulty_rules", self.difficulty_rules())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1437,7 +1456,7 @@ impl<'r> ::core::fmt::Display for ResourcePoolReader<'r> {
     }
 }
 impl<'r> ResourcePoolReader<'r> {
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 12;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1511,11 +1530,23 @@ impl<'r> ResourcePoolReader<'r> {
     pub fn client_assets(&self) -> AssetVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        AssetVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn modifier_pool(&self) -> ChallengeModifierVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        ChallengeModifierVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn difficulty_rules(&self) -> DifficultyRuleVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            AssetVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            DifficultyRuleVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            AssetVecReader::new_unchecked(&self.as_slice()[start..])
+            DifficultyRuleVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1575,6 +1606,8 @@ impl<'r> molecule::prelude::Reader<'r> for ResourcePoolReader<'r> {
         WarriorVecReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
         EnemyVecReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
         AssetVecReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        ChallengeModifierVecReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        DifficultyRuleVecReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
         Ok(())
     }
 }
@@ -1590,9 +1623,11 @@ pub struct ResourcePoolBuilder {
     pub(crate) warrior_pool: WarriorVec,
     pub(crate) enemy_pool: EnemyVec,
     pub(crate) client_assets: AssetVec,
+    pub(crate) modifier_pool: ChallengeModifierVec,
+    pub(crate) difficulty_rules: DifficultyRuleVec,
 }
 impl ResourcePoolBuilder {
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 12;
     pub fn system_pool(mut self, v: SystemVec) -> Self {
         self.system_pool = v;
         self
@@ -1633,6 +1668,14 @@ impl ResourcePoolBuilder {
         self.client_assets = v;
         self
     }
+    pub fn modifier_pool(mut self, v: ChallengeModifierVec) -> Self {
+        self.modifier_pool = v;
+        self
+    }
+    pub fn difficulty_rules(mut self, v: DifficultyRuleVec) -> Self {
+        self.difficulty_rules = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for ResourcePoolBuilder {
     type Entity = ResourcePool;
@@ -1649,6 +1692,8 @@ impl molecule::prelude::Builder for ResourcePoolBuilder {
             + self.warrior_pool.as_slice().len()
             + self.enemy_pool.as_slice().len()
             + self.client_assets.as_slice().len()
+            + self.modifier_pool.as_slice().len()
+            + self.difficulty_rules.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1673,6 +1718,10 @@ impl molecule::prelude::Builder for ResourcePoolBuilder {
         total_size += self.enemy_pool.as_slice().len();
         offsets.push(total_size);
         total_size += self.client_assets.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.modifier_pool.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.difficulty_rules.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -1687,6 +1736,8 @@ impl molecule::prelude::Builder for ResourcePoolBuilder {
         writer.write_all(self.warrior_pool.as_slice())?;
         writer.write_all(self.enemy_pool.as_slice())?;
         writer.write_all(self.client_assets.as_slice())?;
+        writer.write_all(self.modifier_pool.as_slice())?;
+        writer.write_all(self.difficulty_rules.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
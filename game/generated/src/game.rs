@@ -635,6 +635,9 @@ impl ::core::fmt::Display for GameReplay {
             "scene_operation_list",
             self.scene_operation_list()
         )?;
+        write!(f, ", {}: {}", "modifiers", self.modifiers())?;
+        write!(f, ", {}: {}", "difficulty", self.difficulty())?;
+        write!(f, ", {}: {}", "challenge_day", self.challenge_day())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -649,14 +652,15 @@ impl ::core::default::Default for GameReplay {
     }
 }
 impl GameReplay {
-    const DEFAULT_VALUE: [u8; 121] = [
-        121, 0, 0, 0, 16, 0, 0, 0, 117, 0, 0, 0, 117, 0, 0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0,
-        0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0, 0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0,
-        0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0,
-        0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 4, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 146] = [
+        146, 0, 0, 0, 28, 0, 0, 0, 129, 0, 0, 0, 129, 0, 0, 0, 133, 0, 0, 0, 137, 0, 0, 0, 138, 0,
+        0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0,
+        0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0,
+        0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -688,11 +692,29 @@ impl GameReplay {
     pub fn scene_operation_list(&self) -> OperationSetVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        OperationSetVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn modifiers(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ResourceIdVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn difficulty(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn challenge_day(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            OperationSetVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            OperationSetVec::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> GameReplayReader<'r> {
@@ -725,6 +747,9 @@ impl molecule::prelude::Entity for GameReplay {
             .starting_player(self.starting_player())
             .potion(self.potion())
             .scene_operation_list(self.scene_operation_list())
+            .modifiers(self.modifiers())
+            .difficulty(self.difficulty())
+            .challenge_day(self.challenge_day())
     }
 }
 #[derive(Clone, Copy)]
@@ -754,6 +779,9 @@ impl<'r> ::core::fmt::Display for GameReplayReader<'r> {
             "scene_operation_list",
             self.scene_operation_list()
         )?;
+        write!(f, ", {}: {}", "modifiers", self.modifiers())?;
+        write!(f, ", {}: {}", "difficulty", self.difficulty())?;
+        write!(f, ", {}: {}", "challenge_day", self.challenge_day())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -762,7 +790,7 @@ impl<'r> ::core::fmt::Display for GameReplayReader<'r> {
     }
 }
 impl<'r> GameReplayReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -794,11 +822,29 @@ impl<'r> GameReplayReader<'r> {
     pub fn scene_operation_list(&self) -> OperationSetVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        OperationSetVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn modifiers(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn difficulty(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn challenge_day(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            OperationSetVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            OperationSetVecReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -851,6 +897,9 @@ impl<'r> molecule::prelude::Reader<'r> for GameReplayReader<'r> {
         WarriorReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         PotionOptReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         OperationSetVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
@@ -859,9 +908,12 @@ pub struct GameReplayBuilder {
     pub(crate) starting_player: Warrior,
     pub(crate) potion: PotionOpt,
     pub(crate) scene_operation_list: OperationSetVec,
+    pub(crate) modifiers: ResourceIdVec,
+    pub(crate) difficulty: Byte,
+    pub(crate) challenge_day: Uint64,
 }
 impl GameReplayBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
     pub fn starting_player(mut self, v: Warrior) -> Self {
         self.starting_player = v;
         self
@@ -874,6 +926,18 @@ impl GameReplayBuilder {
         self.scene_operation_list = v;
         self
     }
+    pub fn modifiers(mut self, v: ResourceIdVec) -> Self {
+        self.modifiers = v;
+        self
+    }
+    pub fn difficulty(mut self, v: Byte) -> Self {
+        self.difficulty = v;
+        self
+    }
+    pub fn challenge_day(mut self, v: Uint64) -> Self {
+        self.challenge_day = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for GameReplayBuilder {
     type Entity = GameReplay;
@@ -883,6 +947,9 @@ impl molecule::prelude::Builder for GameReplayBuilder {
             + self.starting_player.as_slice().len()
             + self.potion.as_slice().len()
             + self.scene_operation_list.as_slice().len()
+            + self.modifiers.as_slice().len()
+            + self.difficulty.as_slice().len()
+            + self.challenge_day.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -893,6 +960,12 @@ impl molecule::prelude::Builder for GameReplayBuilder {
         total_size += self.potion.as_slice().len();
         offsets.push(total_size);
         total_size += self.scene_operation_list.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.modifiers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.difficulty.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.challenge_day.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -900,6 +973,9 @@ impl molecule::prelude::Builder for GameReplayBuilder {
         writer.write_all(self.starting_player.as_slice())?;
         writer.write_all(self.potion.as_slice())?;
         writer.write_all(self.scene_operation_list.as_slice())?;
+        writer.write_all(self.modifiers.as_slice())?;
+        writer.write_all(self.difficulty.as_slice())?;
+        writer.write_all(self.challenge_day.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1271,6 +1347,7 @@ impl ::core::fmt::Display for GameSnapshot {
         write!(f, ", {}: {}", "player_xy", self.player_xy())?;
         write!(f, ", {}: {}", "max_hp", self.max_hp())?;
         write!(f, ", {}: {}", "scene_level", self.scene_level())?;
+        write!(f, ", {}: {}", "difficulty", self.difficulty())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1285,15 +1362,15 @@ impl ::core::default::Default for GameSnapshot {
     }
 }
 impl GameSnapshot {
-    const DEFAULT_VALUE: [u8; 144] = [
-        144, 0, 0, 0, 24, 0, 0, 0, 125, 0, 0, 0, 127, 0, 0, 0, 141, 0, 0, 0, 143, 0, 0, 0, 101, 0,
-        0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0, 0, 0, 84, 0,
-        0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 91, 0,
-        0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0,
-        0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 149] = [
+        149, 0, 0, 0, 28, 0, 0, 0, 129, 0, 0, 0, 131, 0, 0, 0, 145, 0, 0, 0, 147, 0, 0, 0, 148, 0,
+        0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0,
+        0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0,
+        0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1337,8 +1414,14 @@ impl GameSnapshot {
     pub fn scene_level(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn difficulty(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
+            let end = molecule::unpack_number(&slice[28..]) as usize;
             Byte::new_unchecked(self.0.slice(start..end))
         } else {
             Byte::new_unchecked(self.0.slice(start..))
@@ -1376,6 +1459,7 @@ impl molecule::prelude::Entity for GameSnapshot {
             .player_xy(self.player_xy())
             .max_hp(self.max_hp())
             .scene_level(self.scene_level())
+            .difficulty(self.difficulty())
     }
 }
 #[derive(Clone, Copy)]
@@ -1402,6 +1486,7 @@ impl<'r> ::core::fmt::Display for GameSnapshotReader<'r> {
         write!(f, ", {}: {}", "player_xy", self.player_xy())?;
         write!(f, ", {}: {}", "max_hp", self.max_hp())?;
         write!(f, ", {}: {}", "scene_level", self.scene_level())?;
+        write!(f, ", {}: {}", "difficulty", self.difficulty())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1410,7 +1495,7 @@ impl<'r> ::core::fmt::Display for GameSnapshotReader<'r> {
     }
 }
 impl<'r> GameSnapshotReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1454,8 +1539,14 @@ impl<'r> GameSnapshotReader<'r> {
     pub fn scene_level(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn difficulty(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
+            let end = molecule::unpack_number(&slice[28..]) as usize;
             ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             ByteReader::new_unchecked(&self.as_slice()[start..])
@@ -1513,6 +1604,7 @@ impl<'r> molecule::prelude::Reader<'r> for GameSnapshotReader<'r> {
         CoordinateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         NumberReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
@@ -1523,9 +1615,10 @@ pub struct GameSnapshotBuilder {
     pub(crate) player_xy: Coordinate,
     pub(crate) max_hp: Number,
     pub(crate) scene_level: Byte,
+    pub(crate) difficulty: Byte,
 }
 impl GameSnapshotBuilder {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn current_player(mut self, v: Warrior) -> Self {
         self.current_player = v;
         self
@@ -1546,6 +1639,10 @@ impl GameSnapshotBuilder {
         self.scene_level = v;
         self
     }
+    pub fn difficulty(mut self, v: Byte) -> Self {
+        self.difficulty = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for GameSnapshotBuilder {
     type Entity = GameSnapshot;
@@ -1557,6 +1654,7 @@ impl molecule::prelude::Builder for GameSnapshotBuilder {
             + self.player_xy.as_slice().len()
             + self.max_hp.as_slice().len()
             + self.scene_level.as_slice().len()
+            + self.difficulty.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1571,6 +1669,8 @@ impl molecule::prelude::Builder for GameSnapshotBuilder {
         total_size += self.max_hp.as_slice().len();
         offsets.push(total_size);
         total_size += self.scene_level.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.difficulty.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -1580,6 +1680,7 @@ impl molecule::prelude::Builder for GameSnapshotBuilder {
         writer.write_all(self.player_xy.as_slice())?;
         writer.write_all(self.max_hp.as_slice())?;
         writer.write_all(self.scene_level.as_slice())?;
+        writer.write_all(self.difficulty.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1625,19 +1726,19 @@ impl ::core::default::Default for GameSession {
     }
 }
 impl GameSession {
-    const DEFAULT_VALUE: [u8; 315] = [
-        59, 1, 0, 0, 16, 0, 0, 0, 137, 0, 0, 0, 25, 1, 0, 0, 121, 0, 0, 0, 16, 0, 0, 0, 117, 0, 0,
-        0, 117, 0, 0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0,
-        0, 0, 83, 0, 0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0,
-        0, 0, 90, 0, 0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 144, 0, 0, 0,
-        24, 0, 0, 0, 125, 0, 0, 0, 127, 0, 0, 0, 141, 0, 0, 0, 143, 0, 0, 0, 101, 0, 0, 0, 72, 0,
-        0, 0, 74, 0, 0, 0, 78, 0, 0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0, 0, 0, 84, 0, 0, 0, 85, 0,
-        0, 0, 86, 0, 0, 0, 87, 0, 0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 91, 0, 0, 0, 92, 0,
-        0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 34,
-        0, 0, 0, 24, 0, 0, 0, 26, 0, 0, 0, 28, 0, 0, 0, 30, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 333] = [
+        77, 1, 0, 0, 16, 0, 0, 0, 150, 0, 0, 0, 43, 1, 0, 0, 134, 0, 0, 0, 24, 0, 0, 0, 125, 0, 0,
+        0, 125, 0, 0, 0, 129, 0, 0, 0, 133, 0, 0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0,
+        0, 0, 80, 0, 0, 0, 82, 0, 0, 0, 83, 0, 0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0,
+        0, 0, 88, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 28, 0, 0, 0, 129, 0, 0, 0, 131, 0, 0, 0, 145,
+        0, 0, 0, 147, 0, 0, 0, 148, 0, 0, 0, 101, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 78, 0, 0, 0,
+        80, 0, 0, 0, 82, 0, 0, 0, 83, 0, 0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 86, 0, 0, 0, 87, 0, 0, 0,
+        88, 0, 0, 0, 89, 0, 0, 0, 90, 0, 0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 24, 0, 0, 0, 26,
+        0, 0, 0, 28, 0, 0, 0, 30, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
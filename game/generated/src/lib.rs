@@ -20,7 +20,7 @@ mod casting {
     extern crate alloc;
     use super::*;
     use alloc::vec::Vec;
-    use molecule::prelude::{Byte, Entity};
+    use molecule::prelude::{Builder, Byte, Entity};
 
     impl From<Number> for u16 {
         fn from(value: Number) -> Self {
@@ -57,6 +57,26 @@ mod casting {
             Self::from_le_bytes(value.as_slice().try_into().unwrap())
         }
     }
+
+    impl From<u16> for ResourceId {
+        fn from(value: u16) -> Self {
+            Self::new_unchecked(value.to_le_bytes().to_vec().into())
+        }
+    }
+
+    impl From<Vec<u16>> for ResourceIdVec {
+        fn from(value: Vec<u16>) -> Self {
+            Self::new_builder()
+                .set(value.into_iter().map(Into::into).collect())
+                .build()
+        }
+    }
+
+    impl From<u64> for Uint64 {
+        fn from(value: u64) -> Self {
+            Self::new_unchecked(value.to_le_bytes().to_vec().into())
+        }
+    }
 }
 
 pub use casting::*;
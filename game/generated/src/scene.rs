@@ -3,8 +3,8 @@
 use super::types::*;
 use molecule::prelude::*;
 #[derive(Clone)]
-pub struct NodeEnemy(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeEnemy {
+pub struct NodeEnemyWave(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeEnemyWave {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13,12 +13,12 @@ impl ::core::fmt::LowerHex for NodeEnemy {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeEnemy {
+impl ::core::fmt::Debug for NodeEnemyWave {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeEnemy {
+impl ::core::fmt::Display for NodeEnemyWave {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "count", self.count())?;
@@ -30,13 +30,13 @@ impl ::core::fmt::Display for NodeEnemy {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeEnemy {
+impl ::core::default::Default for NodeEnemyWave {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeEnemy::new_unchecked(v)
+        NodeEnemyWave::new_unchecked(v)
     }
 }
-impl NodeEnemy {
+impl NodeEnemyWave {
     const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
@@ -71,15 +71,1702 @@ impl NodeEnemy {
             ResourceIdVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeEnemyReader<'r> {
-        NodeEnemyReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeEnemyWaveReader<'r> {
+        NodeEnemyWaveReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeEnemyWave {
+    type Builder = NodeEnemyWaveBuilder;
+    const NAME: &'static str = "NodeEnemyWave";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeEnemyWave(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyWaveReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyWaveReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .count(self.count())
+            .enemy_pool(self.enemy_pool())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeEnemyWaveReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeEnemyWaveReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeEnemyWaveReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeEnemyWaveReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeEnemyWaveReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeEnemyWaveReader<'r> {
+    type Entity = NodeEnemyWave;
+    const NAME: &'static str = "NodeEnemyWaveReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeEnemyWaveReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeEnemyWaveBuilder {
+    pub(crate) count: Byte,
+    pub(crate) enemy_pool: ResourceIdVec,
+}
+impl NodeEnemyWaveBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn enemy_pool(mut self, v: ResourceIdVec) -> Self {
+        self.enemy_pool = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeEnemyWaveBuilder {
+    type Entity = NodeEnemyWave;
+    const NAME: &'static str = "NodeEnemyWaveBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.count.as_slice().len()
+            + self.enemy_pool.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.enemy_pool.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.enemy_pool.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeEnemyWave::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeEnemyWaveVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeEnemyWaveVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeEnemyWaveVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeEnemyWaveVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for NodeEnemyWaveVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeEnemyWaveVec::new_unchecked(v)
+    }
+}
+impl NodeEnemyWaveVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<NodeEnemyWave> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> NodeEnemyWave {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            NodeEnemyWave::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            NodeEnemyWave::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeEnemyWaveVecReader<'r> {
+        NodeEnemyWaveVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeEnemyWaveVec {
+    type Builder = NodeEnemyWaveVecBuilder;
+    const NAME: &'static str = "NodeEnemyWaveVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeEnemyWaveVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyWaveVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyWaveVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeEnemyWaveVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeEnemyWaveVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeEnemyWaveVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeEnemyWaveVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> NodeEnemyWaveVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<NodeEnemyWaveReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> NodeEnemyWaveReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            NodeEnemyWaveReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            NodeEnemyWaveReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeEnemyWaveVecReader<'r> {
+    type Entity = NodeEnemyWaveVec;
+    const NAME: &'static str = "NodeEnemyWaveVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeEnemyWaveVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            NodeEnemyWaveReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeEnemyWaveVecBuilder(pub(crate) Vec<NodeEnemyWave>);
+impl NodeEnemyWaveVecBuilder {
+    pub fn set(mut self, v: Vec<NodeEnemyWave>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: NodeEnemyWave) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = NodeEnemyWave>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: NodeEnemyWave) -> Option<NodeEnemyWave> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for NodeEnemyWaveVecBuilder {
+    type Entity = NodeEnemyWaveVec;
+    const NAME: &'static str = "NodeEnemyWaveVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeEnemyWaveVec::new_unchecked(inner.into())
+    }
+}
+pub struct NodeEnemyWaveVecIterator(NodeEnemyWaveVec, usize, usize);
+impl ::core::iter::Iterator for NodeEnemyWaveVecIterator {
+    type Item = NodeEnemyWave;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for NodeEnemyWaveVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for NodeEnemyWaveVec {
+    type Item = NodeEnemyWave;
+    type IntoIter = NodeEnemyWaveVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        NodeEnemyWaveVecIterator(self, 0, len)
+    }
+}
+impl<'r> NodeEnemyWaveVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> NodeEnemyWaveVecReaderIterator<'t, 'r> {
+        NodeEnemyWaveVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct NodeEnemyWaveVecReaderIterator<'t, 'r>(&'t NodeEnemyWaveVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for NodeEnemyWaveVecReaderIterator<'t, 'r> {
+    type Item = NodeEnemyWaveReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for NodeEnemyWaveVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct NodeEnemy(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        write!(f, ", {}: {}", "waves", self.waves())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeEnemy {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeEnemy::new_unchecked(v)
+    }
+}
+impl NodeEnemy {
+    const DEFAULT_VALUE: [u8; 25] = [
+        25, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ResourceIdVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn waves(&self) -> NodeEnemyWaveVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            NodeEnemyWaveVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            NodeEnemyWaveVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeEnemyReader<'r> {
+        NodeEnemyReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeEnemy {
+    type Builder = NodeEnemyBuilder;
+    const NAME: &'static str = "NodeEnemy";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeEnemy(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEnemyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .count(self.count())
+            .enemy_pool(self.enemy_pool())
+            .waves(self.waves())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeEnemyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        write!(f, ", {}: {}", "waves", self.waves())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeEnemyReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn waves(&self) -> NodeEnemyWaveVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            NodeEnemyWaveVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            NodeEnemyWaveVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeEnemyReader<'r> {
+    type Entity = NodeEnemy;
+    const NAME: &'static str = "NodeEnemyReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeEnemyReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        NodeEnemyWaveVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeEnemyBuilder {
+    pub(crate) count: Byte,
+    pub(crate) enemy_pool: ResourceIdVec,
+    pub(crate) waves: NodeEnemyWaveVec,
+}
+impl NodeEnemyBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn enemy_pool(mut self, v: ResourceIdVec) -> Self {
+        self.enemy_pool = v;
+        self
+    }
+    pub fn waves(mut self, v: NodeEnemyWaveVec) -> Self {
+        self.waves = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeEnemyBuilder {
+    type Entity = NodeEnemy;
+    const NAME: &'static str = "NodeEnemyBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.count.as_slice().len()
+            + self.enemy_pool.as_slice().len()
+            + self.waves.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.enemy_pool.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.waves.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.enemy_pool.as_slice())?;
+        writer.write_all(self.waves.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeEnemy::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeEliteEnemy(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeEliteEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeEliteEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeEliteEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeEliteEnemy {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeEliteEnemy::new_unchecked(v)
+    }
+}
+impl NodeEliteEnemy {
+    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            ResourceIdVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeEliteEnemyReader<'r> {
+        NodeEliteEnemyReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeEliteEnemy {
+    type Builder = NodeEliteEnemyBuilder;
+    const NAME: &'static str = "NodeEliteEnemy";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeEliteEnemy(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEliteEnemyReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeEliteEnemyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .count(self.count())
+            .enemy_pool(self.enemy_pool())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeEliteEnemyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeEliteEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeEliteEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeEliteEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeEliteEnemyReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeEliteEnemyReader<'r> {
+    type Entity = NodeEliteEnemy;
+    const NAME: &'static str = "NodeEliteEnemyReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeEliteEnemyReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeEliteEnemyBuilder {
+    pub(crate) count: Byte,
+    pub(crate) enemy_pool: ResourceIdVec,
+}
+impl NodeEliteEnemyBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn enemy_pool(mut self, v: ResourceIdVec) -> Self {
+        self.enemy_pool = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeEliteEnemyBuilder {
+    type Entity = NodeEliteEnemy;
+    const NAME: &'static str = "NodeEliteEnemyBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.count.as_slice().len()
+            + self.enemy_pool.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.enemy_pool.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.enemy_pool.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeEliteEnemy::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeBossEnemy(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeBossEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeBossEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeBossEnemy {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeBossEnemy {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeBossEnemy::new_unchecked(v)
+    }
+}
+impl NodeBossEnemy {
+    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            ResourceIdVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeBossEnemyReader<'r> {
+        NodeBossEnemyReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeBossEnemy {
+    type Builder = NodeBossEnemyBuilder;
+    const NAME: &'static str = "NodeBossEnemy";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeBossEnemy(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeBossEnemyReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeBossEnemyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .count(self.count())
+            .enemy_pool(self.enemy_pool())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeBossEnemyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeBossEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeBossEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeBossEnemyReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeBossEnemyReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn enemy_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeBossEnemyReader<'r> {
+    type Entity = NodeBossEnemy;
+    const NAME: &'static str = "NodeBossEnemyReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeBossEnemyReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeBossEnemyBuilder {
+    pub(crate) count: Byte,
+    pub(crate) enemy_pool: ResourceIdVec,
+}
+impl NodeBossEnemyBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn enemy_pool(mut self, v: ResourceIdVec) -> Self {
+        self.enemy_pool = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeBossEnemyBuilder {
+    type Entity = NodeBossEnemy;
+    const NAME: &'static str = "NodeBossEnemyBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.count.as_slice().len()
+            + self.enemy_pool.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.enemy_pool.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.enemy_pool.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeBossEnemy::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeTreasureChest(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeTreasureChest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeTreasureChest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeTreasureChest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pick", self.pick())?;
+        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "item_pool", self.item_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeTreasureChest {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeTreasureChest::new_unchecked(v)
+    }
+}
+impl NodeTreasureChest {
+    const DEFAULT_VALUE: [u8; 22] = [
+        22, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pick(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn item_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ResourceIdVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            ResourceIdVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeTreasureChestReader<'r> {
+        NodeTreasureChestReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeTreasureChest {
+    type Builder = NodeTreasureChestBuilder;
+    const NAME: &'static str = "NodeTreasureChest";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeTreasureChest(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeTreasureChestReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeTreasureChestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .pick(self.pick())
+            .count(self.count())
+            .item_pool(self.item_pool())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeTreasureChestReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeTreasureChestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeTreasureChestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeTreasureChestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pick", self.pick())?;
+        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "item_pool", self.item_pool())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeTreasureChestReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pick(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn item_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeTreasureChestReader<'r> {
+    type Entity = NodeTreasureChest;
+    const NAME: &'static str = "NodeTreasureChestReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeTreasureChestReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeTreasureChestBuilder {
+    pub(crate) pick: Byte,
+    pub(crate) count: Byte,
+    pub(crate) item_pool: ResourceIdVec,
+}
+impl NodeTreasureChestBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn pick(mut self, v: Byte) -> Self {
+        self.pick = v;
+        self
+    }
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn item_pool(mut self, v: ResourceIdVec) -> Self {
+        self.item_pool = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeTreasureChestBuilder {
+    type Entity = NodeTreasureChest;
+    const NAME: &'static str = "NodeTreasureChestBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.pick.as_slice().len()
+            + self.count.as_slice().len()
+            + self.item_pool.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.pick.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.item_pool.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.pick.as_slice())?;
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.item_pool.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeTreasureChest::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeRecoverPoint(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeRecoverPoint {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeRecoverPoint {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeRecoverPoint {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "hp_percent", self.hp_percent())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeRecoverPoint {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeRecoverPoint::new_unchecked(v)
+    }
+}
+impl NodeRecoverPoint {
+    const DEFAULT_VALUE: [u8; 9] = [9, 0, 0, 0, 8, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn hp_percent(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeRecoverPointReader<'r> {
+        NodeRecoverPointReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeEnemy {
-    type Builder = NodeEnemyBuilder;
-    const NAME: &'static str = "NodeEnemy";
+impl molecule::prelude::Entity for NodeRecoverPoint {
+    type Builder = NodeRecoverPointBuilder;
+    const NAME: &'static str = "NodeRecoverPoint";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeEnemy(data)
+        NodeRecoverPoint(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -88,23 +1775,21 @@ impl molecule::prelude::Entity for NodeEnemy {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeEnemyReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeRecoverPointReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeEnemyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeRecoverPointReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .count(self.count())
-            .enemy_pool(self.enemy_pool())
+        Self::new_builder().hp_percent(self.hp_percent())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeEnemyReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeEnemyReader<'r> {
+pub struct NodeRecoverPointReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeRecoverPointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -113,16 +1798,15 @@ impl<'r> ::core::fmt::LowerHex for NodeEnemyReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeEnemyReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeRecoverPointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeEnemyReader<'r> {
+impl<'r> ::core::fmt::Display for NodeRecoverPointReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "enemy_pool", self.enemy_pool())?;
+        write!(f, "{}: {}", "hp_percent", self.hp_percent())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -130,8 +1814,8 @@ impl<'r> ::core::fmt::Display for NodeEnemyReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeEnemyReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> NodeRecoverPointReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -148,31 +1832,25 @@ impl<'r> NodeEnemyReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn count(&self) -> ByteReader<'r> {
+    pub fn hp_percent(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn enemy_pool(&self) -> ResourceIdVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeEnemyReader<'r> {
-    type Entity = NodeEnemy;
-    const NAME: &'static str = "NodeEnemyReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeRecoverPointReader<'r> {
+    type Entity = NodeRecoverPoint;
+    const NAME: &'static str = "NodeRecoverPointReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeEnemyReader(slice)
+        NodeRecoverPointReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -212,59 +1890,48 @@ impl<'r> molecule::prelude::Reader<'r> for NodeEnemyReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeEnemyBuilder {
-    pub(crate) count: Byte,
-    pub(crate) enemy_pool: ResourceIdVec,
+pub struct NodeRecoverPointBuilder {
+    pub(crate) hp_percent: Byte,
 }
-impl NodeEnemyBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn count(mut self, v: Byte) -> Self {
-        self.count = v;
-        self
-    }
-    pub fn enemy_pool(mut self, v: ResourceIdVec) -> Self {
-        self.enemy_pool = v;
+impl NodeRecoverPointBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn hp_percent(mut self, v: Byte) -> Self {
+        self.hp_percent = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeEnemyBuilder {
-    type Entity = NodeEnemy;
-    const NAME: &'static str = "NodeEnemyBuilder";
+impl molecule::prelude::Builder for NodeRecoverPointBuilder {
+    type Entity = NodeRecoverPoint;
+    const NAME: &'static str = "NodeRecoverPointBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.count.as_slice().len()
-            + self.enemy_pool.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.hp_percent.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.count.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.enemy_pool.as_slice().len();
+        total_size += self.hp_percent.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.count.as_slice())?;
-        writer.write_all(self.enemy_pool.as_slice())?;
+        writer.write_all(self.hp_percent.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeEnemy::new_unchecked(inner.into())
+        NodeRecoverPoint::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeTreasureChest(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeTreasureChest {
+pub struct NodeItemMerchant(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeItemMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -273,16 +1940,15 @@ impl ::core::fmt::LowerHex for NodeTreasureChest {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeTreasureChest {
+impl ::core::fmt::Debug for NodeItemMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeTreasureChest {
+impl ::core::fmt::Display for NodeItemMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "pick", self.pick())?;
-        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, "{}: {}", "count", self.count())?;
         write!(f, ", {}: {}", "item_pool", self.item_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -291,17 +1957,15 @@ impl ::core::fmt::Display for NodeTreasureChest {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeTreasureChest {
+impl ::core::default::Default for NodeItemMerchant {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeTreasureChest::new_unchecked(v)
+        NodeItemMerchant::new_unchecked(v)
     }
 }
-impl NodeTreasureChest {
-    const DEFAULT_VALUE: [u8; 22] = [
-        22, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const FIELD_COUNT: usize = 3;
+impl NodeItemMerchant {
+    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -318,37 +1982,31 @@ impl NodeTreasureChest {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn pick(&self) -> Byte {
+    pub fn count(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
     pub fn item_pool(&self) -> ResourceIdVec {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
+            let end = molecule::unpack_number(&slice[12..]) as usize;
             ResourceIdVec::new_unchecked(self.0.slice(start..end))
         } else {
             ResourceIdVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeTreasureChestReader<'r> {
-        NodeTreasureChestReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeItemMerchantReader<'r> {
+        NodeItemMerchantReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeTreasureChest {
-    type Builder = NodeTreasureChestBuilder;
-    const NAME: &'static str = "NodeTreasureChest";
+impl molecule::prelude::Entity for NodeItemMerchant {
+    type Builder = NodeItemMerchantBuilder;
+    const NAME: &'static str = "NodeItemMerchant";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeTreasureChest(data)
+        NodeItemMerchant(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -357,24 +2015,23 @@ impl molecule::prelude::Entity for NodeTreasureChest {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeTreasureChestReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeItemMerchantReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeTreasureChestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeItemMerchantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .pick(self.pick())
             .count(self.count())
             .item_pool(self.item_pool())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeTreasureChestReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeTreasureChestReader<'r> {
+pub struct NodeItemMerchantReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeItemMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -383,16 +2040,15 @@ impl<'r> ::core::fmt::LowerHex for NodeTreasureChestReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeTreasureChestReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeItemMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeTreasureChestReader<'r> {
+impl<'r> ::core::fmt::Display for NodeItemMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "pick", self.pick())?;
-        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, "{}: {}", "count", self.count())?;
         write!(f, ", {}: {}", "item_pool", self.item_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -401,8 +2057,8 @@ impl<'r> ::core::fmt::Display for NodeTreasureChestReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeTreasureChestReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> NodeItemMerchantReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -419,37 +2075,31 @@ impl<'r> NodeTreasureChestReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn pick(&self) -> ByteReader<'r> {
+    pub fn count(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
         ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
     pub fn item_pool(&self) -> ResourceIdVecReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
+            let end = molecule::unpack_number(&slice[12..]) as usize;
             ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeTreasureChestReader<'r> {
-    type Entity = NodeTreasureChest;
-    const NAME: &'static str = "NodeTreasureChestReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeItemMerchantReader<'r> {
+    type Entity = NodeItemMerchant;
+    const NAME: &'static str = "NodeItemMerchantReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeTreasureChestReader(slice)
+        NodeItemMerchantReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -489,23 +2139,17 @@ impl<'r> molecule::prelude::Reader<'r> for NodeTreasureChestReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ResourceIdVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeTreasureChestBuilder {
-    pub(crate) pick: Byte,
+pub struct NodeItemMerchantBuilder {
     pub(crate) count: Byte,
     pub(crate) item_pool: ResourceIdVec,
 }
-impl NodeTreasureChestBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn pick(mut self, v: Byte) -> Self {
-        self.pick = v;
-        self
-    }
+impl NodeItemMerchantBuilder {
+    pub const FIELD_COUNT: usize = 2;
     pub fn count(mut self, v: Byte) -> Self {
         self.count = v;
         self
@@ -515,12 +2159,11 @@ impl NodeTreasureChestBuilder {
         self
     }
 }
-impl molecule::prelude::Builder for NodeTreasureChestBuilder {
-    type Entity = NodeTreasureChest;
-    const NAME: &'static str = "NodeTreasureChestBuilder";
+impl molecule::prelude::Builder for NodeItemMerchantBuilder {
+    type Entity = NodeItemMerchant;
+    const NAME: &'static str = "NodeItemMerchantBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.pick.as_slice().len()
             + self.count.as_slice().len()
             + self.item_pool.as_slice().len()
     }
@@ -528,8 +2171,6 @@ impl molecule::prelude::Builder for NodeTreasureChestBuilder {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.pick.as_slice().len();
-        offsets.push(total_size);
         total_size += self.count.as_slice().len();
         offsets.push(total_size);
         total_size += self.item_pool.as_slice().len();
@@ -537,7 +2178,6 @@ impl molecule::prelude::Builder for NodeTreasureChestBuilder {
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.pick.as_slice())?;
         writer.write_all(self.count.as_slice())?;
         writer.write_all(self.item_pool.as_slice())?;
         Ok(())
@@ -546,12 +2186,12 @@ impl molecule::prelude::Builder for NodeTreasureChestBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeTreasureChest::new_unchecked(inner.into())
+        NodeItemMerchant::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeRecoverPoint(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeRecoverPoint {
+pub struct NodeCardMerchant(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeCardMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -560,15 +2200,16 @@ impl ::core::fmt::LowerHex for NodeRecoverPoint {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeRecoverPoint {
+impl ::core::fmt::Debug for NodeCardMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeRecoverPoint {
+impl ::core::fmt::Display for NodeCardMerchant {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hp_percent", self.hp_percent())?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -576,15 +2217,15 @@ impl ::core::fmt::Display for NodeRecoverPoint {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeRecoverPoint {
+impl ::core::default::Default for NodeCardMerchant {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeRecoverPoint::new_unchecked(v)
+        NodeCardMerchant::new_unchecked(v)
     }
 }
-impl NodeRecoverPoint {
-    const DEFAULT_VALUE: [u8; 9] = [9, 0, 0, 0, 8, 0, 0, 0, 0];
-    pub const FIELD_COUNT: usize = 1;
+impl NodeCardMerchant {
+    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -601,25 +2242,31 @@ impl NodeRecoverPoint {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn hp_percent(&self) -> Byte {
+    pub fn count(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn card_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVec::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            ResourceIdVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeRecoverPointReader<'r> {
-        NodeRecoverPointReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeCardMerchantReader<'r> {
+        NodeCardMerchantReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeRecoverPoint {
-    type Builder = NodeRecoverPointBuilder;
-    const NAME: &'static str = "NodeRecoverPoint";
+impl molecule::prelude::Entity for NodeCardMerchant {
+    type Builder = NodeCardMerchantBuilder;
+    const NAME: &'static str = "NodeCardMerchant";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeRecoverPoint(data)
+        NodeCardMerchant(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -628,21 +2275,23 @@ impl molecule::prelude::Entity for NodeRecoverPoint {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeRecoverPointReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeCardMerchantReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeRecoverPointReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeCardMerchantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().hp_percent(self.hp_percent())
+        Self::new_builder()
+            .count(self.count())
+            .card_pool(self.card_pool())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeRecoverPointReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeRecoverPointReader<'r> {
+pub struct NodeCardMerchantReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeCardMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -651,15 +2300,16 @@ impl<'r> ::core::fmt::LowerHex for NodeRecoverPointReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeRecoverPointReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeCardMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeRecoverPointReader<'r> {
+impl<'r> ::core::fmt::Display for NodeCardMerchantReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hp_percent", self.hp_percent())?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -667,8 +2317,8 @@ impl<'r> ::core::fmt::Display for NodeRecoverPointReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeRecoverPointReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+impl<'r> NodeCardMerchantReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -685,25 +2335,31 @@ impl<'r> NodeRecoverPointReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn hp_percent(&self) -> ByteReader<'r> {
+    pub fn count(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn card_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeRecoverPointReader<'r> {
-    type Entity = NodeRecoverPoint;
-    const NAME: &'static str = "NodeRecoverPointReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeCardMerchantReader<'r> {
+    type Entity = NodeCardMerchant;
+    const NAME: &'static str = "NodeCardMerchantReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeRecoverPointReader(slice)
+        NodeCardMerchantReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -743,48 +2399,59 @@ impl<'r> molecule::prelude::Reader<'r> for NodeRecoverPointReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeRecoverPointBuilder {
-    pub(crate) hp_percent: Byte,
+pub struct NodeCardMerchantBuilder {
+    pub(crate) count: Byte,
+    pub(crate) card_pool: ResourceIdVec,
 }
-impl NodeRecoverPointBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn hp_percent(mut self, v: Byte) -> Self {
-        self.hp_percent = v;
+impl NodeCardMerchantBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn count(mut self, v: Byte) -> Self {
+        self.count = v;
+        self
+    }
+    pub fn card_pool(mut self, v: ResourceIdVec) -> Self {
+        self.card_pool = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeRecoverPointBuilder {
-    type Entity = NodeRecoverPoint;
-    const NAME: &'static str = "NodeRecoverPointBuilder";
+impl molecule::prelude::Builder for NodeCardMerchantBuilder {
+    type Entity = NodeCardMerchant;
+    const NAME: &'static str = "NodeCardMerchantBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.hp_percent.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.count.as_slice().len()
+            + self.card_pool.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.hp_percent.as_slice().len();
+        total_size += self.count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.card_pool.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.hp_percent.as_slice())?;
+        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.card_pool.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeRecoverPoint::new_unchecked(inner.into())
+        NodeCardMerchant::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeItemMerchant(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeItemMerchant {
+pub struct NodeUnknown(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeUnknown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -793,16 +2460,16 @@ impl ::core::fmt::LowerHex for NodeItemMerchant {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeItemMerchant {
+impl ::core::fmt::Debug for NodeUnknown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeItemMerchant {
+impl ::core::fmt::Display for NodeUnknown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "item_pool", self.item_pool())?;
+        write!(f, ", {}: {}", "system_pool", self.system_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -810,13 +2477,13 @@ impl ::core::fmt::Display for NodeItemMerchant {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeItemMerchant {
+impl ::core::default::Default for NodeUnknown {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeItemMerchant::new_unchecked(v)
+        NodeUnknown::new_unchecked(v)
     }
 }
-impl NodeItemMerchant {
+impl NodeUnknown {
     const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
@@ -841,7 +2508,7 @@ impl NodeItemMerchant {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte::new_unchecked(self.0.slice(start..end))
     }
-    pub fn item_pool(&self) -> ResourceIdVec {
+    pub fn system_pool(&self) -> ResourceIdVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
@@ -851,15 +2518,15 @@ impl NodeItemMerchant {
             ResourceIdVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeItemMerchantReader<'r> {
-        NodeItemMerchantReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeUnknownReader<'r> {
+        NodeUnknownReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeItemMerchant {
-    type Builder = NodeItemMerchantBuilder;
-    const NAME: &'static str = "NodeItemMerchant";
+impl molecule::prelude::Entity for NodeUnknown {
+    type Builder = NodeUnknownBuilder;
+    const NAME: &'static str = "NodeUnknown";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeItemMerchant(data)
+        NodeUnknown(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -868,10 +2535,10 @@ impl molecule::prelude::Entity for NodeItemMerchant {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeItemMerchantReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeUnknownReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeItemMerchantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeUnknownReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -879,12 +2546,12 @@ impl molecule::prelude::Entity for NodeItemMerchant {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .count(self.count())
-            .item_pool(self.item_pool())
+            .system_pool(self.system_pool())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeItemMerchantReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeItemMerchantReader<'r> {
+pub struct NodeUnknownReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeUnknownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -893,16 +2560,16 @@ impl<'r> ::core::fmt::LowerHex for NodeItemMerchantReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeItemMerchantReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeUnknownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeItemMerchantReader<'r> {
+impl<'r> ::core::fmt::Display for NodeUnknownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "item_pool", self.item_pool())?;
+        write!(f, ", {}: {}", "system_pool", self.system_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -910,7 +2577,7 @@ impl<'r> ::core::fmt::Display for NodeItemMerchantReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeItemMerchantReader<'r> {
+impl<'r> NodeUnknownReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -934,7 +2601,7 @@ impl<'r> NodeItemMerchantReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn item_pool(&self) -> ResourceIdVecReader<'r> {
+    pub fn system_pool(&self) -> ResourceIdVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
@@ -945,14 +2612,14 @@ impl<'r> NodeItemMerchantReader<'r> {
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeItemMerchantReader<'r> {
-    type Entity = NodeItemMerchant;
-    const NAME: &'static str = "NodeItemMerchantReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeUnknownReader<'r> {
+    type Entity = NodeUnknown;
+    const NAME: &'static str = "NodeUnknownReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeItemMerchantReader(slice)
+        NodeUnknownReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -997,28 +2664,28 @@ impl<'r> molecule::prelude::Reader<'r> for NodeItemMerchantReader<'r> {
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeItemMerchantBuilder {
+pub struct NodeUnknownBuilder {
     pub(crate) count: Byte,
-    pub(crate) item_pool: ResourceIdVec,
+    pub(crate) system_pool: ResourceIdVec,
 }
-impl NodeItemMerchantBuilder {
+impl NodeUnknownBuilder {
     pub const FIELD_COUNT: usize = 2;
     pub fn count(mut self, v: Byte) -> Self {
         self.count = v;
         self
     }
-    pub fn item_pool(mut self, v: ResourceIdVec) -> Self {
-        self.item_pool = v;
+    pub fn system_pool(mut self, v: ResourceIdVec) -> Self {
+        self.system_pool = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeItemMerchantBuilder {
-    type Entity = NodeItemMerchant;
-    const NAME: &'static str = "NodeItemMerchantBuilder";
+impl molecule::prelude::Builder for NodeUnknownBuilder {
+    type Entity = NodeUnknown;
+    const NAME: &'static str = "NodeUnknownBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.count.as_slice().len()
-            + self.item_pool.as_slice().len()
+            + self.system_pool.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1026,25 +2693,25 @@ impl molecule::prelude::Builder for NodeItemMerchantBuilder {
         offsets.push(total_size);
         total_size += self.count.as_slice().len();
         offsets.push(total_size);
-        total_size += self.item_pool.as_slice().len();
+        total_size += self.system_pool.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.count.as_slice())?;
-        writer.write_all(self.item_pool.as_slice())?;
+        writer.write_all(self.system_pool.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeItemMerchant::new_unchecked(inner.into())
+        NodeUnknown::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeCardMerchant(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeCardMerchant {
+pub struct NodeCampsite(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeCampsite {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1053,16 +2720,15 @@ impl ::core::fmt::LowerHex for NodeCardMerchant {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeCardMerchant {
+impl ::core::fmt::Debug for NodeCampsite {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeCardMerchant {
+impl ::core::fmt::Display for NodeCampsite {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
+        write!(f, "{}: {}", "card_system", self.card_system())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1070,15 +2736,15 @@ impl ::core::fmt::Display for NodeCardMerchant {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeCardMerchant {
+impl ::core::default::Default for NodeCampsite {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeCardMerchant::new_unchecked(v)
+        NodeCampsite::new_unchecked(v)
     }
 }
-impl NodeCardMerchant {
-    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
-    pub const FIELD_COUNT: usize = 2;
+impl NodeCampsite {
+    const DEFAULT_VALUE: [u8; 10] = [10, 0, 0, 0, 8, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1095,31 +2761,25 @@ impl NodeCardMerchant {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn count(&self) -> Byte {
+    pub fn card_system(&self) -> ResourceId {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn card_pool(&self) -> ResourceIdVec {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ResourceIdVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            ResourceId::new_unchecked(self.0.slice(start..end))
         } else {
-            ResourceIdVec::new_unchecked(self.0.slice(start..))
+            ResourceId::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeCardMerchantReader<'r> {
-        NodeCardMerchantReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeCampsiteReader<'r> {
+        NodeCampsiteReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeCardMerchant {
-    type Builder = NodeCardMerchantBuilder;
-    const NAME: &'static str = "NodeCardMerchant";
+impl molecule::prelude::Entity for NodeCampsite {
+    type Builder = NodeCampsiteBuilder;
+    const NAME: &'static str = "NodeCampsite";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeCardMerchant(data)
+        NodeCampsite(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1128,23 +2788,21 @@ impl molecule::prelude::Entity for NodeCardMerchant {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeCardMerchantReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeCampsiteReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeCardMerchantReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeCampsiteReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .count(self.count())
-            .card_pool(self.card_pool())
+        Self::new_builder().card_system(self.card_system())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeCardMerchantReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeCardMerchantReader<'r> {
+pub struct NodeCampsiteReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeCampsiteReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1153,16 +2811,15 @@ impl<'r> ::core::fmt::LowerHex for NodeCardMerchantReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeCardMerchantReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeCampsiteReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeCardMerchantReader<'r> {
+impl<'r> ::core::fmt::Display for NodeCampsiteReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
+        write!(f, "{}: {}", "card_system", self.card_system())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1170,8 +2827,8 @@ impl<'r> ::core::fmt::Display for NodeCardMerchantReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeCardMerchantReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> NodeCampsiteReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1188,31 +2845,25 @@ impl<'r> NodeCardMerchantReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn count(&self) -> ByteReader<'r> {
+    pub fn card_system(&self) -> ResourceIdReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn card_pool(&self) -> ResourceIdVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            ResourceIdReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+            ResourceIdReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeCardMerchantReader<'r> {
-    type Entity = NodeCardMerchant;
-    const NAME: &'static str = "NodeCardMerchantReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeCampsiteReader<'r> {
+    type Entity = NodeCampsite;
+    const NAME: &'static str = "NodeCampsiteReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeCardMerchantReader(slice)
+        NodeCampsiteReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1251,60 +2902,49 @@ impl<'r> molecule::prelude::Reader<'r> for NodeCardMerchantReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ResourceIdReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeCardMerchantBuilder {
-    pub(crate) count: Byte,
-    pub(crate) card_pool: ResourceIdVec,
+pub struct NodeCampsiteBuilder {
+    pub(crate) card_system: ResourceId,
 }
-impl NodeCardMerchantBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn count(mut self, v: Byte) -> Self {
-        self.count = v;
-        self
-    }
-    pub fn card_pool(mut self, v: ResourceIdVec) -> Self {
-        self.card_pool = v;
+impl NodeCampsiteBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn card_system(mut self, v: ResourceId) -> Self {
+        self.card_system = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeCardMerchantBuilder {
-    type Entity = NodeCardMerchant;
-    const NAME: &'static str = "NodeCardMerchantBuilder";
+impl molecule::prelude::Builder for NodeCampsiteBuilder {
+    type Entity = NodeCampsite;
+    const NAME: &'static str = "NodeCampsiteBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.count.as_slice().len()
-            + self.card_pool.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.card_system.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.count.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.card_pool.as_slice().len();
+        total_size += self.card_system.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.count.as_slice())?;
-        writer.write_all(self.card_pool.as_slice())?;
+        writer.write_all(self.card_system.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeCardMerchant::new_unchecked(inner.into())
+        NodeCampsite::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeUnknown(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeUnknown {
+pub struct NodeCardShrine(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeCardShrine {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1313,16 +2953,16 @@ impl ::core::fmt::LowerHex for NodeUnknown {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeUnknown {
+impl ::core::fmt::Debug for NodeCardShrine {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeUnknown {
+impl ::core::fmt::Display for NodeCardShrine {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "system_pool", self.system_pool())?;
+        write!(f, "{}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "price_increase", self.price_increase())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1330,14 +2970,17 @@ impl ::core::fmt::Display for NodeUnknown {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeUnknown {
+impl ::core::default::Default for NodeCardShrine {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeUnknown::new_unchecked(v)
+        NodeCardShrine::new_unchecked(v)
     }
 }
-impl NodeUnknown {
-    const DEFAULT_VALUE: [u8; 17] = [17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+impl NodeCardShrine {
+    const DEFAULT_VALUE: [u8; 30] = [
+        30, 0, 0, 0, 12, 0, 0, 0, 28, 0, 0, 0, 16, 0, 0, 0, 12, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -1355,31 +2998,31 @@ impl NodeUnknown {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn count(&self) -> Byte {
+    pub fn price(&self) -> RandomNumber {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
+        RandomNumber::new_unchecked(self.0.slice(start..end))
     }
-    pub fn system_pool(&self) -> ResourceIdVec {
+    pub fn price_increase(&self) -> Number {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            ResourceIdVec::new_unchecked(self.0.slice(start..end))
+            Number::new_unchecked(self.0.slice(start..end))
         } else {
-            ResourceIdVec::new_unchecked(self.0.slice(start..))
+            Number::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeUnknownReader<'r> {
-        NodeUnknownReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeCardShrineReader<'r> {
+        NodeCardShrineReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeUnknown {
-    type Builder = NodeUnknownBuilder;
-    const NAME: &'static str = "NodeUnknown";
+impl molecule::prelude::Entity for NodeCardShrine {
+    type Builder = NodeCardShrineBuilder;
+    const NAME: &'static str = "NodeCardShrine";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeUnknown(data)
+        NodeCardShrine(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1388,23 +3031,23 @@ impl molecule::prelude::Entity for NodeUnknown {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeUnknownReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeCardShrineReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeUnknownReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeCardShrineReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .count(self.count())
-            .system_pool(self.system_pool())
+            .price(self.price())
+            .price_increase(self.price_increase())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeUnknownReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeUnknownReader<'r> {
+pub struct NodeCardShrineReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeCardShrineReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1413,16 +3056,16 @@ impl<'r> ::core::fmt::LowerHex for NodeUnknownReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeUnknownReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeCardShrineReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeUnknownReader<'r> {
+impl<'r> ::core::fmt::Display for NodeCardShrineReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "count", self.count())?;
-        write!(f, ", {}: {}", "system_pool", self.system_pool())?;
+        write!(f, "{}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "price_increase", self.price_increase())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1430,7 +3073,7 @@ impl<'r> ::core::fmt::Display for NodeUnknownReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeUnknownReader<'r> {
+impl<'r> NodeCardShrineReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -1448,31 +3091,31 @@ impl<'r> NodeUnknownReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn count(&self) -> ByteReader<'r> {
+    pub fn price(&self) -> RandomNumberReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+        RandomNumberReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn system_pool(&self) -> ResourceIdVecReader<'r> {
+    pub fn price_increase(&self) -> NumberReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
+            NumberReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
+            NumberReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeUnknownReader<'r> {
-    type Entity = NodeUnknown;
-    const NAME: &'static str = "NodeUnknownReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeCardShrineReader<'r> {
+    type Entity = NodeCardShrine;
+    const NAME: &'static str = "NodeCardShrineReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeUnknownReader(slice)
+        NodeCardShrineReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1511,60 +3154,60 @@ impl<'r> molecule::prelude::Reader<'r> for NodeUnknownReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ResourceIdVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        RandomNumberReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        NumberReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeUnknownBuilder {
-    pub(crate) count: Byte,
-    pub(crate) system_pool: ResourceIdVec,
+pub struct NodeCardShrineBuilder {
+    pub(crate) price: RandomNumber,
+    pub(crate) price_increase: Number,
 }
-impl NodeUnknownBuilder {
+impl NodeCardShrineBuilder {
     pub const FIELD_COUNT: usize = 2;
-    pub fn count(mut self, v: Byte) -> Self {
-        self.count = v;
+    pub fn price(mut self, v: RandomNumber) -> Self {
+        self.price = v;
         self
     }
-    pub fn system_pool(mut self, v: ResourceIdVec) -> Self {
-        self.system_pool = v;
+    pub fn price_increase(mut self, v: Number) -> Self {
+        self.price_increase = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeUnknownBuilder {
-    type Entity = NodeUnknown;
-    const NAME: &'static str = "NodeUnknownBuilder";
+impl molecule::prelude::Builder for NodeCardShrineBuilder {
+    type Entity = NodeCardShrine;
+    const NAME: &'static str = "NodeCardShrineBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.count.as_slice().len()
-            + self.system_pool.as_slice().len()
+            + self.price.as_slice().len()
+            + self.price_increase.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.count.as_slice().len();
+        total_size += self.price.as_slice().len();
         offsets.push(total_size);
-        total_size += self.system_pool.as_slice().len();
+        total_size += self.price_increase.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.count.as_slice())?;
-        writer.write_all(self.system_pool.as_slice())?;
+        writer.write_all(self.price.as_slice())?;
+        writer.write_all(self.price_increase.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeUnknown::new_unchecked(inner.into())
+        NodeCardShrine::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeCampsite(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeCampsite {
+pub struct NodeCardAltar(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeCardAltar {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1573,15 +3216,17 @@ impl ::core::fmt::LowerHex for NodeCampsite {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeCampsite {
+impl ::core::fmt::Debug for NodeCardAltar {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeCampsite {
+impl ::core::fmt::Display for NodeCardAltar {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "card_system", self.card_system())?;
+        write!(f, "{}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "price_increase", self.price_increase())?;
+        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1589,15 +3234,18 @@ impl ::core::fmt::Display for NodeCampsite {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeCampsite {
+impl ::core::default::Default for NodeCardAltar {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeCampsite::new_unchecked(v)
+        NodeCardAltar::new_unchecked(v)
     }
 }
-impl NodeCampsite {
-    const DEFAULT_VALUE: [u8; 10] = [10, 0, 0, 0, 8, 0, 0, 0, 0, 0];
-    pub const FIELD_COUNT: usize = 1;
+impl NodeCardAltar {
+    const DEFAULT_VALUE: [u8; 38] = [
+        38, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 34, 0, 0, 0, 16, 0, 0, 0, 12, 0, 0, 0, 14, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1614,25 +3262,37 @@ impl NodeCampsite {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn card_system(&self) -> ResourceId {
+    pub fn price(&self) -> RandomNumber {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RandomNumber::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn price_increase(&self) -> Number {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Number::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn card_pool(&self) -> ResourceIdVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            ResourceId::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ResourceIdVec::new_unchecked(self.0.slice(start..end))
         } else {
-            ResourceId::new_unchecked(self.0.slice(start..))
+            ResourceIdVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeCampsiteReader<'r> {
-        NodeCampsiteReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeCardAltarReader<'r> {
+        NodeCardAltarReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeCampsite {
-    type Builder = NodeCampsiteBuilder;
-    const NAME: &'static str = "NodeCampsite";
+impl molecule::prelude::Entity for NodeCardAltar {
+    type Builder = NodeCardAltarBuilder;
+    const NAME: &'static str = "NodeCardAltar";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeCampsite(data)
+        NodeCardAltar(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1641,21 +3301,24 @@ impl molecule::prelude::Entity for NodeCampsite {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeCampsiteReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeCardAltarReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeCampsiteReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        NodeCardAltarReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().card_system(self.card_system())
+        Self::new_builder()
+            .price(self.price())
+            .price_increase(self.price_increase())
+            .card_pool(self.card_pool())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeCampsiteReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeCampsiteReader<'r> {
+pub struct NodeCardAltarReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeCardAltarReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1664,15 +3327,17 @@ impl<'r> ::core::fmt::LowerHex for NodeCampsiteReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeCampsiteReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeCardAltarReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeCampsiteReader<'r> {
+impl<'r> ::core::fmt::Display for NodeCardAltarReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "card_system", self.card_system())?;
+        write!(f, "{}: {}", "price", self.price())?;
+        write!(f, ", {}: {}", "price_increase", self.price_increase())?;
+        write!(f, ", {}: {}", "card_pool", self.card_pool())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1680,8 +3345,8 @@ impl<'r> ::core::fmt::Display for NodeCampsiteReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeCampsiteReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+impl<'r> NodeCardAltarReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1698,25 +3363,37 @@ impl<'r> NodeCampsiteReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn card_system(&self) -> ResourceIdReader<'r> {
+    pub fn price(&self) -> RandomNumberReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RandomNumberReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn price_increase(&self) -> NumberReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        NumberReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn card_pool(&self) -> ResourceIdVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            ResourceIdReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ResourceIdReader::new_unchecked(&self.as_slice()[start..])
+            ResourceIdVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeCampsiteReader<'r> {
-    type Entity = NodeCampsite;
-    const NAME: &'static str = "NodeCampsiteReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeCardAltarReader<'r> {
+    type Entity = NodeCardAltar;
+    const NAME: &'static str = "NodeCardAltarReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeCampsiteReader(slice)
+        NodeCardAltarReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1755,44 +3432,65 @@ impl<'r> molecule::prelude::Reader<'r> for NodeCampsiteReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ResourceIdReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        RandomNumberReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        NumberReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ResourceIdVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeCampsiteBuilder {
-    pub(crate) card_system: ResourceId,
+pub struct NodeCardAltarBuilder {
+    pub(crate) price: RandomNumber,
+    pub(crate) price_increase: Number,
+    pub(crate) card_pool: ResourceIdVec,
 }
-impl NodeCampsiteBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn card_system(mut self, v: ResourceId) -> Self {
-        self.card_system = v;
+impl NodeCardAltarBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn price(mut self, v: RandomNumber) -> Self {
+        self.price = v;
+        self
+    }
+    pub fn price_increase(mut self, v: Number) -> Self {
+        self.price_increase = v;
+        self
+    }
+    pub fn card_pool(mut self, v: ResourceIdVec) -> Self {
+        self.card_pool = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeCampsiteBuilder {
-    type Entity = NodeCampsite;
-    const NAME: &'static str = "NodeCampsiteBuilder";
+impl molecule::prelude::Builder for NodeCardAltarBuilder {
+    type Entity = NodeCardAltar;
+    const NAME: &'static str = "NodeCardAltarBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.card_system.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.price.as_slice().len()
+            + self.price_increase.as_slice().len()
+            + self.card_pool.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.card_system.as_slice().len();
+        total_size += self.price.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.price_increase.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.card_pool.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.card_system.as_slice())?;
+        writer.write_all(self.price.as_slice())?;
+        writer.write_all(self.price_increase.as_slice())?;
+        writer.write_all(self.card_pool.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeCampsite::new_unchecked(inner.into())
+        NodeCardAltar::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -2356,10 +4054,10 @@ impl ::core::default::Default for NodeInstance {
     }
 }
 impl NodeInstance {
-    const DEFAULT_VALUE: [u8; 21] = [
-        0, 0, 0, 0, 17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 29] = [
+        0, 0, 0, 0, 25, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
     ];
-    pub const ITEMS_COUNT: usize = 10;
+    pub const ITEMS_COUNT: usize = 14;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -2376,6 +4074,10 @@ impl NodeInstance {
             7 => NodeBarrier::new_unchecked(inner).into(),
             8 => NodeStartingPoint::new_unchecked(inner).into(),
             9 => NodeTargetingPoint::new_unchecked(inner).into(),
+            10 => NodeCardShrine::new_unchecked(inner).into(),
+            11 => NodeCardAltar::new_unchecked(inner).into(),
+            12 => NodeEliteEnemy::new_unchecked(inner).into(),
+            13 => NodeBossEnemy::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -2432,7 +4134,7 @@ impl<'r> ::core::fmt::Display for NodeInstanceReader<'r> {
     }
 }
 impl<'r> NodeInstanceReader<'r> {
-    pub const ITEMS_COUNT: usize = 10;
+    pub const ITEMS_COUNT: usize = 14;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -2449,6 +4151,10 @@ impl<'r> NodeInstanceReader<'r> {
             7 => NodeBarrierReader::new_unchecked(inner).into(),
             8 => NodeStartingPointReader::new_unchecked(inner).into(),
             9 => NodeTargetingPointReader::new_unchecked(inner).into(),
+            10 => NodeCardShrineReader::new_unchecked(inner).into(),
+            11 => NodeCardAltarReader::new_unchecked(inner).into(),
+            12 => NodeEliteEnemyReader::new_unchecked(inner).into(),
+            13 => NodeBossEnemyReader::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -2484,6 +4190,10 @@ impl<'r> molecule::prelude::Reader<'r> for NodeInstanceReader<'r> {
             7 => NodeBarrierReader::verify(inner_slice, compatible),
             8 => NodeStartingPointReader::verify(inner_slice, compatible),
             9 => NodeTargetingPointReader::verify(inner_slice, compatible),
+            10 => NodeCardShrineReader::verify(inner_slice, compatible),
+            11 => NodeCardAltarReader::verify(inner_slice, compatible),
+            12 => NodeEliteEnemyReader::verify(inner_slice, compatible),
+            13 => NodeBossEnemyReader::verify(inner_slice, compatible),
             _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
         }?;
         Ok(())
@@ -2492,7 +4202,7 @@ impl<'r> molecule::prelude::Reader<'r> for NodeInstanceReader<'r> {
 #[derive(Clone, Debug, Default)]
 pub struct NodeInstanceBuilder(pub(crate) NodeInstanceUnion);
 impl NodeInstanceBuilder {
-    pub const ITEMS_COUNT: usize = 10;
+    pub const ITEMS_COUNT: usize = 14;
     pub fn set<I>(mut self, v: I) -> Self
     where
         I: ::core::convert::Into<NodeInstanceUnion>,
@@ -2530,6 +4240,10 @@ pub enum NodeInstanceUnion {
     NodeBarrier(NodeBarrier),
     NodeStartingPoint(NodeStartingPoint),
     NodeTargetingPoint(NodeTargetingPoint),
+    NodeCardShrine(NodeCardShrine),
+    NodeCardAltar(NodeCardAltar),
+    NodeEliteEnemy(NodeEliteEnemy),
+    NodeBossEnemy(NodeBossEnemy),
 }
 #[derive(Debug, Clone, Copy)]
 pub enum NodeInstanceUnionReader<'r> {
@@ -2543,6 +4257,10 @@ pub enum NodeInstanceUnionReader<'r> {
     NodeBarrier(NodeBarrierReader<'r>),
     NodeStartingPoint(NodeStartingPointReader<'r>),
     NodeTargetingPoint(NodeTargetingPointReader<'r>),
+    NodeCardShrine(NodeCardShrineReader<'r>),
+    NodeCardAltar(NodeCardAltarReader<'r>),
+    NodeEliteEnemy(NodeEliteEnemyReader<'r>),
+    NodeBossEnemy(NodeBossEnemyReader<'r>),
 }
 impl ::core::default::Default for NodeInstanceUnion {
     fn default() -> Self {
@@ -2582,6 +4300,18 @@ impl ::core::fmt::Display for NodeInstanceUnion {
             NodeInstanceUnion::NodeTargetingPoint(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, NodeTargetingPoint::NAME, item)
             }
+            NodeInstanceUnion::NodeCardShrine(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeCardShrine::NAME, item)
+            }
+            NodeInstanceUnion::NodeCardAltar(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeCardAltar::NAME, item)
+            }
+            NodeInstanceUnion::NodeEliteEnemy(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeEliteEnemy::NAME, item)
+            }
+            NodeInstanceUnion::NodeBossEnemy(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeBossEnemy::NAME, item)
+            }
         }
     }
 }
@@ -2618,6 +4348,18 @@ impl<'r> ::core::fmt::Display for NodeInstanceUnionReader<'r> {
             NodeInstanceUnionReader::NodeTargetingPoint(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, NodeTargetingPoint::NAME, item)
             }
+            NodeInstanceUnionReader::NodeCardShrine(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeCardShrine::NAME, item)
+            }
+            NodeInstanceUnionReader::NodeCardAltar(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeCardAltar::NAME, item)
+            }
+            NodeInstanceUnionReader::NodeEliteEnemy(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeEliteEnemy::NAME, item)
+            }
+            NodeInstanceUnionReader::NodeBossEnemy(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeBossEnemy::NAME, item)
+            }
         }
     }
 }
@@ -2634,6 +4376,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(ref item) => write!(f, "{}", item),
             NodeInstanceUnion::NodeStartingPoint(ref item) => write!(f, "{}", item),
             NodeInstanceUnion::NodeTargetingPoint(ref item) => write!(f, "{}", item),
+            NodeInstanceUnion::NodeCardShrine(ref item) => write!(f, "{}", item),
+            NodeInstanceUnion::NodeCardAltar(ref item) => write!(f, "{}", item),
+            NodeInstanceUnion::NodeEliteEnemy(ref item) => write!(f, "{}", item),
+            NodeInstanceUnion::NodeBossEnemy(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -2650,6 +4396,10 @@ impl<'r> NodeInstanceUnionReader<'r> {
             NodeInstanceUnionReader::NodeBarrier(ref item) => write!(f, "{}", item),
             NodeInstanceUnionReader::NodeStartingPoint(ref item) => write!(f, "{}", item),
             NodeInstanceUnionReader::NodeTargetingPoint(ref item) => write!(f, "{}", item),
+            NodeInstanceUnionReader::NodeCardShrine(ref item) => write!(f, "{}", item),
+            NodeInstanceUnionReader::NodeCardAltar(ref item) => write!(f, "{}", item),
+            NodeInstanceUnionReader::NodeEliteEnemy(ref item) => write!(f, "{}", item),
+            NodeInstanceUnionReader::NodeBossEnemy(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -2703,6 +4453,26 @@ impl ::core::convert::From<NodeTargetingPoint> for NodeInstanceUnion {
         NodeInstanceUnion::NodeTargetingPoint(item)
     }
 }
+impl ::core::convert::From<NodeCardShrine> for NodeInstanceUnion {
+    fn from(item: NodeCardShrine) -> Self {
+        NodeInstanceUnion::NodeCardShrine(item)
+    }
+}
+impl ::core::convert::From<NodeCardAltar> for NodeInstanceUnion {
+    fn from(item: NodeCardAltar) -> Self {
+        NodeInstanceUnion::NodeCardAltar(item)
+    }
+}
+impl ::core::convert::From<NodeEliteEnemy> for NodeInstanceUnion {
+    fn from(item: NodeEliteEnemy) -> Self {
+        NodeInstanceUnion::NodeEliteEnemy(item)
+    }
+}
+impl ::core::convert::From<NodeBossEnemy> for NodeInstanceUnion {
+    fn from(item: NodeBossEnemy) -> Self {
+        NodeInstanceUnion::NodeBossEnemy(item)
+    }
+}
 impl<'r> ::core::convert::From<NodeEnemyReader<'r>> for NodeInstanceUnionReader<'r> {
     fn from(item: NodeEnemyReader<'r>) -> Self {
         NodeInstanceUnionReader::NodeEnemy(item)
@@ -2753,6 +4523,26 @@ impl<'r> ::core::convert::From<NodeTargetingPointReader<'r>> for NodeInstanceUni
         NodeInstanceUnionReader::NodeTargetingPoint(item)
     }
 }
+impl<'r> ::core::convert::From<NodeCardShrineReader<'r>> for NodeInstanceUnionReader<'r> {
+    fn from(item: NodeCardShrineReader<'r>) -> Self {
+        NodeInstanceUnionReader::NodeCardShrine(item)
+    }
+}
+impl<'r> ::core::convert::From<NodeCardAltarReader<'r>> for NodeInstanceUnionReader<'r> {
+    fn from(item: NodeCardAltarReader<'r>) -> Self {
+        NodeInstanceUnionReader::NodeCardAltar(item)
+    }
+}
+impl<'r> ::core::convert::From<NodeEliteEnemyReader<'r>> for NodeInstanceUnionReader<'r> {
+    fn from(item: NodeEliteEnemyReader<'r>) -> Self {
+        NodeInstanceUnionReader::NodeEliteEnemy(item)
+    }
+}
+impl<'r> ::core::convert::From<NodeBossEnemyReader<'r>> for NodeInstanceUnionReader<'r> {
+    fn from(item: NodeBossEnemyReader<'r>) -> Self {
+        NodeInstanceUnionReader::NodeBossEnemy(item)
+    }
+}
 impl NodeInstanceUnion {
     pub const NAME: &'static str = "NodeInstanceUnion";
     pub fn as_bytes(&self) -> molecule::bytes::Bytes {
@@ -2767,6 +4557,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(item) => item.as_bytes(),
             NodeInstanceUnion::NodeStartingPoint(item) => item.as_bytes(),
             NodeInstanceUnion::NodeTargetingPoint(item) => item.as_bytes(),
+            NodeInstanceUnion::NodeCardShrine(item) => item.as_bytes(),
+            NodeInstanceUnion::NodeCardAltar(item) => item.as_bytes(),
+            NodeInstanceUnion::NodeEliteEnemy(item) => item.as_bytes(),
+            NodeInstanceUnion::NodeBossEnemy(item) => item.as_bytes(),
         }
     }
     pub fn as_slice(&self) -> &[u8] {
@@ -2781,6 +4575,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(item) => item.as_slice(),
             NodeInstanceUnion::NodeStartingPoint(item) => item.as_slice(),
             NodeInstanceUnion::NodeTargetingPoint(item) => item.as_slice(),
+            NodeInstanceUnion::NodeCardShrine(item) => item.as_slice(),
+            NodeInstanceUnion::NodeCardAltar(item) => item.as_slice(),
+            NodeInstanceUnion::NodeEliteEnemy(item) => item.as_slice(),
+            NodeInstanceUnion::NodeBossEnemy(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -2795,6 +4593,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(_) => 7,
             NodeInstanceUnion::NodeStartingPoint(_) => 8,
             NodeInstanceUnion::NodeTargetingPoint(_) => 9,
+            NodeInstanceUnion::NodeCardShrine(_) => 10,
+            NodeInstanceUnion::NodeCardAltar(_) => 11,
+            NodeInstanceUnion::NodeEliteEnemy(_) => 12,
+            NodeInstanceUnion::NodeBossEnemy(_) => 13,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -2809,6 +4611,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(_) => "NodeBarrier",
             NodeInstanceUnion::NodeStartingPoint(_) => "NodeStartingPoint",
             NodeInstanceUnion::NodeTargetingPoint(_) => "NodeTargetingPoint",
+            NodeInstanceUnion::NodeCardShrine(_) => "NodeCardShrine",
+            NodeInstanceUnion::NodeCardAltar(_) => "NodeCardAltar",
+            NodeInstanceUnion::NodeEliteEnemy(_) => "NodeEliteEnemy",
+            NodeInstanceUnion::NodeBossEnemy(_) => "NodeBossEnemy",
         }
     }
     pub fn as_reader<'r>(&'r self) -> NodeInstanceUnionReader<'r> {
@@ -2823,6 +4629,10 @@ impl NodeInstanceUnion {
             NodeInstanceUnion::NodeBarrier(item) => item.as_reader().into(),
             NodeInstanceUnion::NodeStartingPoint(item) => item.as_reader().into(),
             NodeInstanceUnion::NodeTargetingPoint(item) => item.as_reader().into(),
+            NodeInstanceUnion::NodeCardShrine(item) => item.as_reader().into(),
+            NodeInstanceUnion::NodeCardAltar(item) => item.as_reader().into(),
+            NodeInstanceUnion::NodeEliteEnemy(item) => item.as_reader().into(),
+            NodeInstanceUnion::NodeBossEnemy(item) => item.as_reader().into(),
         }
     }
 }
@@ -2840,6 +4650,10 @@ impl<'r> NodeInstanceUnionReader<'r> {
             NodeInstanceUnionReader::NodeBarrier(item) => item.as_slice(),
             NodeInstanceUnionReader::NodeStartingPoint(item) => item.as_slice(),
             NodeInstanceUnionReader::NodeTargetingPoint(item) => item.as_slice(),
+            NodeInstanceUnionReader::NodeCardShrine(item) => item.as_slice(),
+            NodeInstanceUnionReader::NodeCardAltar(item) => item.as_slice(),
+            NodeInstanceUnionReader::NodeEliteEnemy(item) => item.as_slice(),
+            NodeInstanceUnionReader::NodeBossEnemy(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -2854,6 +4668,10 @@ impl<'r> NodeInstanceUnionReader<'r> {
             NodeInstanceUnionReader::NodeBarrier(_) => 7,
             NodeInstanceUnionReader::NodeStartingPoint(_) => 8,
             NodeInstanceUnionReader::NodeTargetingPoint(_) => 9,
+            NodeInstanceUnionReader::NodeCardShrine(_) => 10,
+            NodeInstanceUnionReader::NodeCardAltar(_) => 11,
+            NodeInstanceUnionReader::NodeEliteEnemy(_) => 12,
+            NodeInstanceUnionReader::NodeBossEnemy(_) => 13,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -2868,6 +4686,10 @@ impl<'r> NodeInstanceUnionReader<'r> {
             NodeInstanceUnionReader::NodeBarrier(_) => "NodeBarrier",
             NodeInstanceUnionReader::NodeStartingPoint(_) => "NodeStartingPoint",
             NodeInstanceUnionReader::NodeTargetingPoint(_) => "NodeTargetingPoint",
+            NodeInstanceUnionReader::NodeCardShrine(_) => "NodeCardShrine",
+            NodeInstanceUnionReader::NodeCardAltar(_) => "NodeCardAltar",
+            NodeInstanceUnionReader::NodeEliteEnemy(_) => "NodeEliteEnemy",
+            NodeInstanceUnionReader::NodeBossEnemy(_) => "NodeBossEnemy",
         }
     }
 }
@@ -3165,9 +4987,10 @@ impl ::core::default::Default for LevelNode {
     }
 }
 impl LevelNode {
-    const DEFAULT_VALUE: [u8; 52] = [
-        52, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 31, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 60] = [
+        60, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 31, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+        0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
@@ -4378,6 +6201,7 @@ impl ::core::fmt::Display for ScenePartition {
         write!(f, ", {}: {}", "end_point", self.end_point())?;
         write!(f, ", {}: {}", "count", self.count())?;
         write!(f, ", {}: {}", "node_pool", self.node_pool())?;
+        write!(f, ", {}: {}", "rarity", self.rarity())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4392,12 +6216,12 @@ impl ::core::default::Default for ScenePartition {
     }
 }
 impl ScenePartition {
-    const DEFAULT_VALUE: [u8; 66] = [
-        66, 0, 0, 0, 20, 0, 0, 0, 34, 0, 0, 0, 48, 0, 0, 0, 62, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0,
-        13, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0,
-        13, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 71] = [
+        71, 0, 0, 0, 24, 0, 0, 0, 38, 0, 0, 0, 52, 0, 0, 0, 66, 0, 0, 0, 70, 0, 0, 0, 14, 0, 0, 0,
+        12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+        12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4435,11 +6259,17 @@ impl ScenePartition {
     pub fn node_pool(&self) -> LevelNodeVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        LevelNodeVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn rarity(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            LevelNodeVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            LevelNodeVec::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> ScenePartitionReader<'r> {
@@ -4473,6 +6303,7 @@ impl molecule::prelude::Entity for ScenePartition {
             .end_point(self.end_point())
             .count(self.count())
             .node_pool(self.node_pool())
+            .rarity(self.rarity())
     }
 }
 #[derive(Clone, Copy)]
@@ -4498,6 +6329,7 @@ impl<'r> ::core::fmt::Display for ScenePartitionReader<'r> {
         write!(f, ", {}: {}", "end_point", self.end_point())?;
         write!(f, ", {}: {}", "count", self.count())?;
         write!(f, ", {}: {}", "node_pool", self.node_pool())?;
+        write!(f, ", {}: {}", "rarity", self.rarity())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4506,7 +6338,7 @@ impl<'r> ::core::fmt::Display for ScenePartitionReader<'r> {
     }
 }
 impl<'r> ScenePartitionReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4544,11 +6376,17 @@ impl<'r> ScenePartitionReader<'r> {
     pub fn node_pool(&self) -> LevelNodeVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        LevelNodeVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn rarity(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            LevelNodeVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            LevelNodeVecReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -4602,6 +6440,7 @@ impl<'r> molecule::prelude::Reader<'r> for ScenePartitionReader<'r> {
         CoordinateReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         RandomByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         LevelNodeVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
@@ -4611,9 +6450,10 @@ pub struct ScenePartitionBuilder {
     pub(crate) end_point: Coordinate,
     pub(crate) count: RandomByte,
     pub(crate) node_pool: LevelNodeVec,
+    pub(crate) rarity: Byte,
 }
 impl ScenePartitionBuilder {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn start_point(mut self, v: Coordinate) -> Self {
         self.start_point = v;
         self
@@ -4630,6 +6470,10 @@ impl ScenePartitionBuilder {
         self.node_pool = v;
         self
     }
+    pub fn rarity(mut self, v: Byte) -> Self {
+        self.rarity = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for ScenePartitionBuilder {
     type Entity = ScenePartition;
@@ -4640,6 +6484,7 @@ impl molecule::prelude::Builder for ScenePartitionBuilder {
             + self.end_point.as_slice().len()
             + self.count.as_slice().len()
             + self.node_pool.as_slice().len()
+            + self.rarity.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -4652,6 +6497,8 @@ impl molecule::prelude::Builder for ScenePartitionBuilder {
         total_size += self.count.as_slice().len();
         offsets.push(total_size);
         total_size += self.node_pool.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.rarity.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -4660,6 +6507,7 @@ impl molecule::prelude::Builder for ScenePartitionBuilder {
         writer.write_all(self.end_point.as_slice())?;
         writer.write_all(self.count.as_slice())?;
         writer.write_all(self.node_pool.as_slice())?;
+        writer.write_all(self.rarity.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -5044,10 +6892,11 @@ impl ::core::default::Default for FixedLevelNode {
     }
 }
 impl FixedLevelNode {
-    const DEFAULT_VALUE: [u8; 78] = [
-        78, 0, 0, 0, 12, 0, 0, 0, 26, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 52, 0,
+    const DEFAULT_VALUE: [u8; 86] = [
+        86, 0, 0, 0, 12, 0, 0, 0, 26, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 60, 0,
         0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 31, 0, 0, 0, 0, 14, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 25, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0,
+        0,
     ];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
@@ -5635,6 +7484,7 @@ impl ::core::fmt::Display for MapScene {
         write!(f, ", {}: {}", "height", self.height())?;
         write!(f, ", {}: {}", "fixed_nodes", self.fixed_nodes())?;
         write!(f, ", {}: {}", "partition_list", self.partition_list())?;
+        write!(f, ", {}: {}", "rarity", self.rarity())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5649,11 +7499,11 @@ impl ::core::default::Default for MapScene {
     }
 }
 impl MapScene {
-    const DEFAULT_VALUE: [u8; 36] = [
-        36, 0, 0, 0, 24, 0, 0, 0, 26, 0, 0, 0, 27, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0,
-        4, 0, 0, 0, 4, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 41] = [
+        41, 0, 0, 0, 28, 0, 0, 0, 30, 0, 0, 0, 31, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0,
+        0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5697,11 +7547,17 @@ impl MapScene {
     pub fn partition_list(&self) -> ScenePartitionVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ScenePartitionVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn rarity(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            ScenePartitionVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            ScenePartitionVec::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> MapSceneReader<'r> {
@@ -5736,6 +7592,7 @@ impl molecule::prelude::Entity for MapScene {
             .height(self.height())
             .fixed_nodes(self.fixed_nodes())
             .partition_list(self.partition_list())
+            .rarity(self.rarity())
     }
 }
 #[derive(Clone, Copy)]
@@ -5762,6 +7619,7 @@ impl<'r> ::core::fmt::Display for MapSceneReader<'r> {
         write!(f, ", {}: {}", "height", self.height())?;
         write!(f, ", {}: {}", "fixed_nodes", self.fixed_nodes())?;
         write!(f, ", {}: {}", "partition_list", self.partition_list())?;
+        write!(f, ", {}: {}", "rarity", self.rarity())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5770,7 +7628,7 @@ impl<'r> ::core::fmt::Display for MapSceneReader<'r> {
     }
 }
 impl<'r> MapSceneReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5814,11 +7672,17 @@ impl<'r> MapSceneReader<'r> {
     pub fn partition_list(&self) -> ScenePartitionVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ScenePartitionVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn rarity(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            ScenePartitionVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ScenePartitionVecReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -5873,6 +7737,7 @@ impl<'r> molecule::prelude::Reader<'r> for MapSceneReader<'r> {
         ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         FixedLevelNodeVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         ScenePartitionVecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
@@ -5883,9 +7748,10 @@ pub struct MapSceneBuilder {
     pub(crate) height: Byte,
     pub(crate) fixed_nodes: FixedLevelNodeVec,
     pub(crate) partition_list: ScenePartitionVec,
+    pub(crate) rarity: Byte,
 }
 impl MapSceneBuilder {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn id(mut self, v: ResourceId) -> Self {
         self.id = v;
         self
@@ -5906,6 +7772,10 @@ impl MapSceneBuilder {
         self.partition_list = v;
         self
     }
+    pub fn rarity(mut self, v: Byte) -> Self {
+        self.rarity = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for MapSceneBuilder {
     type Entity = MapScene;
@@ -5917,6 +7787,7 @@ impl molecule::prelude::Builder for MapSceneBuilder {
             + self.height.as_slice().len()
             + self.fixed_nodes.as_slice().len()
             + self.partition_list.as_slice().len()
+            + self.rarity.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -5931,6 +7802,8 @@ impl molecule::prelude::Builder for MapSceneBuilder {
         total_size += self.fixed_nodes.as_slice().len();
         offsets.push(total_size);
         total_size += self.partition_list.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.rarity.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -5940,6 +7813,7 @@ impl molecule::prelude::Builder for MapSceneBuilder {
         writer.write_all(self.height.as_slice())?;
         writer.write_all(self.fixed_nodes.as_slice())?;
         writer.write_all(self.partition_list.as_slice())?;
+        writer.write_all(self.rarity.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1,11 +1,34 @@
 #[cfg(test)]
 mod test {
     use lazy_static::lazy_static;
+    use molecule::prelude::{Builder, Entity};
+    use rand::RngCore;
     use spore_warriors_core::battle::pve::MapBattlePVE;
-    use spore_warriors_core::battle::traits::{IterationInput, Selection, SimplePVE};
-    use spore_warriors_core::contexts::{WarriorContext, WarriorDeckContext};
-    use spore_warriors_core::game::Game;
-    use spore_warriors_core::wrappings::{Enemy, Point};
+    use spore_warriors_core::battle::pvp::{decode_opponent, MapBattlePVP, OPPONENT_OFFSET};
+    use spore_warriors_core::battle::stream::{
+        decode_fight_stream, encode_fight_stream, FIGHT_STREAM_VERSION,
+    };
+    use spore_warriors_core::battle::traits::{
+        BattleObserver, BattleState, FightLog, IterationInput, IterationOutput, Selection,
+        SimplePVE, SimplePVP,
+    };
+    use spore_warriors_core::contexts::{
+        CtxAdaptor, EnemyContext, SystemContext, WarriorContext, WarriorDeckContext,
+    };
+    use spore_warriors_core::errors::Error;
+    use spore_warriors_core::game::{Game, SporeRng, DAILY_MODIFIER_COUNT};
+    use spore_warriors_core::map::{MapSkeleton, MoveResult};
+    use spore_warriors_core::systems::{SystemInput, SystemObjects, SystemReturn};
+    use spore_warriors_core::wrappings::{
+        randomized_weighted_selection, Action, ActionCondition, ActionRule, ActionStrategy, Card,
+        ChallengeModifier, DifficultyClass, DifficultyRule, Duration, Enemy, EnemyPhase, EnemyRank,
+        EquipmentSlot, Item, ItemClass, LevelNode, ModifierClass, Node, Point, RequireTarget,
+        System, SystemId, Value, CARD_KEYWORD_ETHEREAL, CARD_KEYWORD_EXHAUST, CARD_KEYWORD_INNATE,
+        CARD_KEYWORD_RETAIN, CARD_OFFSET_START,
+    };
+    use spore_warriors_generated as generated;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     lazy_static! {
         pub static ref RAW_RESOURCE_POOL: Vec<u8> =
@@ -15,7 +38,7 @@ mod test {
     #[test]
     fn test_map_skeleton() -> eyre::Result<()> {
         let point = Point::from_xy(1, 0);
-        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000)?;
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
         let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
         println!("[map] = {:?}", game.map);
         println!(
@@ -34,7 +57,7 @@ mod test {
 
     #[test]
     fn test_pve_fight() -> eyre::Result<()> {
-        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000)?;
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
         let enemies = {
             let resource_pool = &game.controller.resource_pool;
             let enemy = resource_pool.enemy_pool().get_unchecked(0);
@@ -43,7 +66,7 @@ mod test {
         };
         let point = Point::from_xy(1, 0);
         let (player, player_deck) = game.new_session(5001, point, None)?;
-        let mut battle = MapBattlePVE::create(player, player_deck, enemies)?;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies])?;
         let (output, logs) = battle.start(&mut game.controller)?;
         println!("===START===");
         println!("[logs] = {logs:?}");
@@ -69,7 +92,7 @@ mod test {
     #[test]
     fn test_context_encode_decode() -> eyre::Result<()> {
         let point = Point::from_xy(1, 0);
-        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086)?;
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086, 0)?;
         let (player, player_deck) = game.new_session(5001, point, None)?;
         let decoded_player: WarriorContext = rlp::decode(&rlp::encode(&player).to_vec())?;
         assert_eq!(player, decoded_player);
@@ -82,11 +105,1987 @@ mod test {
     #[test]
     fn test_json_encode() -> eyre::Result<()> {
         let point = Point::from_xy(1, 0);
-        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086)?;
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086, 0)?;
         let (player, player_deck) = game.new_session(5001, point, None)?;
         println!("[MAP] = {}", serde_json::to_string_pretty(&game.map)?);
         println!("[PLAYER] = {}", serde_json::to_string_pretty(&player)?);
         println!("[DECK] = {}", serde_json::to_string_pretty(&player_deck)?);
         Ok(())
     }
+
+    #[test]
+    fn test_custom_system() -> eyre::Result<()> {
+        fn double_gold(
+            _: &generated::ResourcePool,
+            _: &mut SporeRng,
+            _: SystemContext,
+            objects: &mut SystemObjects,
+            _: Option<SystemInput>,
+        ) -> Result<SystemReturn, Error> {
+            let player = objects.caster()?.warrior()?;
+            player.gold *= 2;
+            Ok(SystemReturn::Continue(vec![]))
+        }
+
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086, 0)?;
+        game.controller.register_system(1000, double_gold)?;
+        assert!(game.controller.register_system(1000, double_gold).is_err());
+        assert!(game.controller.register_system(0, double_gold).is_err());
+        let (mut player, _) = game.new_session(5001, point, None)?;
+        let gold = player.gold;
+        let system = System {
+            id: 0,
+            system_id: SystemId::try_from(1000)?,
+            args: vec![],
+            duration: None,
+            target_type: RequireTarget::Owner,
+        };
+        let caster = player.offset();
+        let objects: Vec<&mut dyn CtxAdaptor> = vec![&mut player];
+        let mut objects = SystemObjects::new(caster, &[], objects)?;
+        game.controller
+            .system_call(system.into(), &mut objects, None)?;
+        assert_eq!(player.gold, gold * 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_hp_to_attack() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let idle = ActionStrategy {
+            random_select: false,
+            actions: vec![Action {
+                random_select: false,
+                system_pool: vec![],
+            }],
+            rules: vec![],
+        };
+        let mut enemies = vec![];
+        for _ in 0..2 {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            let mut enemy = Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?;
+            enemy.strategy = idle.clone();
+            enemies.push(enemy);
+        }
+        let system = |system_id, args, duration, target_type| System {
+            id: 0,
+            system_id,
+            args,
+            duration,
+            target_type,
+        };
+        let point = Point::from_xy(1, 0);
+        let (mut player, player_deck) = game.new_session(5001, point, None)?;
+        player.hp = player.max_hp - 20;
+        player.props_list = vec![Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 0,
+            price: 0,
+            system_pool: vec![
+                system(
+                    SystemId::TriggerRecoverHpAttack,
+                    vec![],
+                    Some(Duration {
+                        trigger: 25,
+                        count: 2,
+                    }),
+                    RequireTarget::Opponent,
+                ),
+                system(
+                    SystemId::InstantHealing,
+                    vec![Value(5)],
+                    None,
+                    RequireTarget::Owner,
+                ),
+            ],
+            slot: EquipmentSlot::Weapon,
+        }];
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies])?;
+        battle.start(&mut game.controller)?;
+        let (_, logs) = battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), Some(1))],
+            &mut game.controller,
+        )?;
+        // the second enemy is selected at casting, and the healing turns to damage on it
+        assert!(logs.iter().any(|log| matches!(
+            log,
+            FightLog::AddSystem(0, ctx) if ctx.register_3 == vec![2]
+        )));
+        let damaged = logs
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::SystemDamage(0, target, _) => Some(*target),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(damaged, vec![2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_damage_over_time() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let mut enemy = EnemyContext::new(enemy, 1);
+        enemy.shield = 0;
+        enemy.armor = 5;
+        let (mut player, _) = game.new_session(5001, point, None)?;
+        let poison = System {
+            id: 0,
+            system_id: SystemId::TriggerPoison,
+            args: vec![Value(3), Value(2)],
+            duration: Some(Duration {
+                trigger: 3,
+                count: 2,
+            }),
+            target_type: RequireTarget::Opponent,
+        };
+        let (caster, target) = (player.offset(), enemy.offset());
+        for _ in 0..2 {
+            let objects: Vec<&mut dyn CtxAdaptor> = vec![&mut player, &mut enemy];
+            let mut objects = SystemObjects::new(caster, &[target], objects)?;
+            game.controller
+                .system_call(poison.clone().into(), &mut objects, None)?;
+        }
+        assert_eq!(enemy.mounting_systems.len(), 1);
+        assert_eq!(enemy.mounting_systems[0].duration_counter, 4);
+
+        let hp = enemy.hp;
+        let ctx = enemy.mounting_systems[0].clone();
+        let objects: Vec<&mut dyn CtxAdaptor> = vec![&mut enemy];
+        let mut objects = SystemObjects::new(target, &[], objects)?;
+        let input = SystemInput::Trigger(FightLog::PlayerTurn(2));
+        game.controller
+            .system_call(ctx, &mut objects, Some(input))?;
+        assert_eq!(enemy.hp, hp - 3);
+        assert_eq!(enemy.armor, 5);
+        assert_eq!(enemy.mounting_systems[0].duration_counter, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_enemy_poison_battle() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let mut enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let poison = System {
+            id: 0,
+            system_id: SystemId::TriggerPoison,
+            args: vec![Value(2), Value(3)],
+            duration: Some(Duration {
+                trigger: 3,
+                count: 2,
+            }),
+            target_type: RequireTarget::Opponent,
+        };
+        enemy.strategy = ActionStrategy {
+            random_select: false,
+            actions: vec![Action {
+                random_select: false,
+                system_pool: vec![poison],
+            }],
+            rules: vec![],
+        };
+        let point = Point::from_xy(1, 0);
+        let (player, player_deck) = game.new_session(5001, point, None)?;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        let mut poisoned = 0;
+        for _ in 0..4 {
+            let (output, logs) =
+                battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+            assert_eq!(output, IterationOutput::Continue);
+            poisoned += logs
+                .iter()
+                .filter(|log| matches!(log, FightLog::SystemPoisonDamage(0, 2)))
+                .count();
+        }
+        assert_eq!(poisoned, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_thorns_and_reflect() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10086, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let mut enemy = EnemyContext::new(enemy, 1);
+        enemy.shield = 0;
+        enemy.armor = 0;
+        let (mut player, _) = game.new_session(5001, point, None)?;
+        let (owner, attacker) = (player.offset(), enemy.offset());
+        for (system_id, value, expected_damage) in [
+            (SystemId::TriggerThorns, 3, 3),
+            (SystemId::TriggerReflect, 50, 5),
+        ] {
+            let system = System {
+                id: 0,
+                system_id,
+                args: vec![Value(value)],
+                duration: Some(Duration {
+                    trigger: 20,
+                    count: 1,
+                }),
+                target_type: RequireTarget::Owner,
+            };
+            let objects: Vec<&mut dyn CtxAdaptor> = vec![&mut player];
+            let mut objects = SystemObjects::new(owner, &[owner], objects)?;
+            game.controller
+                .system_call(system.into(), &mut objects, None)?;
+            let ctx = player.mounting_systems.pop().expect("mounted system");
+            player.mounting_systems.push(ctx.clone());
+
+            let hp = enemy.hp;
+            let objects: Vec<&mut dyn CtxAdaptor> = vec![&mut player, &mut enemy];
+            let mut objects = SystemObjects::with_source(owner, Some(attacker), &[], objects)?;
+            let input = SystemInput::Trigger(FightLog::SystemDamage(attacker, owner, 10));
+            game.controller
+                .system_call(ctx, &mut objects, Some(input))?;
+            assert_eq!(enemy.hp, hp - expected_damage);
+            assert!(player.mounting_systems.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_counter_attack_battle() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let system = |system_id, value, duration, target_type| System {
+            id: 0,
+            system_id,
+            args: vec![Value(value)],
+            duration,
+            target_type,
+        };
+        let mut enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        enemy.strategy = ActionStrategy {
+            random_select: false,
+            actions: vec![Action {
+                random_select: false,
+                system_pool: vec![system(
+                    SystemId::InstantDamage,
+                    5,
+                    None,
+                    RequireTarget::Opponent,
+                )],
+            }],
+            rules: vec![],
+        };
+        let duration = Some(Duration {
+            trigger: 20,
+            count: 5,
+        });
+        let point = Point::from_xy(1, 0);
+        let (mut player, player_deck) = game.new_session(5001, point, None)?;
+        player.props_list = vec![Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 0,
+            price: 0,
+            system_pool: vec![
+                system(SystemId::TriggerThorns, 3, duration, RequireTarget::Owner),
+                system(
+                    SystemId::TriggerReflect,
+                    100,
+                    duration,
+                    RequireTarget::Owner,
+                ),
+            ],
+            slot: EquipmentSlot::Weapon,
+        }];
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy.clone()]])?;
+        battle.start(&mut game.controller)?;
+        battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), None)],
+            &mut game.controller,
+        )?;
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+
+        // the enemy hit reaches both counter attacks through the triggered damage log once
+        let hits = logs
+            .iter()
+            .filter(|log| matches!(log, FightLog::SystemDamage(1, 0, _)))
+            .count();
+        assert_eq!(hits, 1);
+        let counters = logs
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::SystemThornsDamage(offset, _) => Some((*offset, true)),
+                FightLog::SystemReflectDamage(offset, _) => Some((*offset, false)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(counters, vec![(1, true), (1, false)]);
+
+        // a single charge survives the owner's own attack, and is only taken by the hit after
+        let (mut player, player_deck) = game.new_session(5001, point, None)?;
+        let thorns = Duration {
+            trigger: 20,
+            count: 1,
+        };
+        player.props_list = [
+            system(
+                SystemId::TriggerThorns,
+                3,
+                Some(thorns),
+                RequireTarget::Owner,
+            ),
+            system(SystemId::InstantDamage, 1, None, RequireTarget::Opponent),
+        ]
+        .into_iter()
+        .map(|system| Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 0,
+            price: 0,
+            system_pool: vec![system],
+            slot: EquipmentSlot::Weapon,
+        })
+        .collect();
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), None)],
+            &mut game.controller,
+        )?;
+        let (_, logs) = battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), Some(0))],
+            &mut game.controller,
+        )?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemDamage(0, 1, _))));
+        assert!(!logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemThornsDamage(_, _))));
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemThornsDamage(1, 3))));
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::RemoveSystem(0, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_control_effects() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let control = |system_id, trigger| System {
+            id: 0,
+            system_id,
+            args: vec![],
+            duration: Some(Duration { trigger, count: 1 }),
+            target_type: RequireTarget::Opponent,
+        };
+        let attack = System {
+            id: 0,
+            system_id: SystemId::InstantDamage,
+            args: vec![Value(5)],
+            duration: None,
+            target_type: RequireTarget::Opponent,
+        };
+        let enemy_with = |game: &mut Game, actions: Vec<Vec<System>>| {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            let mut enemy = Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?;
+            enemy.strategy = ActionStrategy {
+                random_select: false,
+                actions: actions
+                    .into_iter()
+                    .map(|system_pool| Action {
+                        random_select: false,
+                        system_pool,
+                    })
+                    .collect(),
+                rules: vec![],
+            };
+            Ok::<_, Error>(enemy)
+        };
+        let point = Point::from_xy(1, 0);
+
+        // stunned enemy skips its action in the next enemy turn only
+        let enemy = enemy_with(&mut game, vec![vec![attack.clone()]])?;
+        let (mut player, player_deck) = game.new_session(5001, point, None)?;
+        player.props_list = vec![Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 0,
+            price: 0,
+            system_pool: vec![control(SystemId::TriggerStun, 2)],
+            slot: EquipmentSlot::Weapon,
+        }];
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), Some(0))],
+            &mut game.controller,
+        )?;
+        let attacked = |logs: &[FightLog]| {
+            logs.iter()
+                .any(|log| matches!(log, FightLog::SystemDamage(1, 0, _)))
+        };
+        let stunned = |logs: &[FightLog]| {
+            logs.iter()
+                .any(|log| matches!(log, FightLog::EnemyStunned(1)))
+        };
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(stunned(&logs) && !attacked(&logs));
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(!stunned(&logs) && attacked(&logs));
+
+        // silenced player cannot use the special card until the next enemy turn
+        let enemy = enemy_with(
+            &mut game,
+            vec![vec![control(SystemId::TriggerSilence, 2)], vec![]],
+        )?;
+        let (player, player_deck) = game.new_session(5001, point, None)?;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        let special_use = |battle: &mut MapBattlePVE, game: &mut Game| {
+            battle.run(
+                vec![IterationInput::SpecialCardUse(Some(0))],
+                &mut game.controller,
+            )
+        };
+        assert!(matches!(
+            special_use(&mut battle, &mut game),
+            Err(Error::BattleSpecialCardSilenced)
+        ));
+        battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(!matches!(
+            special_use(&mut battle, &mut game),
+            Err(Error::BattleSpecialCardSilenced)
+        ));
+
+        // freezing the player lands on the next drawn card
+        let enemy = enemy_with(&mut game, vec![vec![control(SystemId::TriggerFreeze, 3)]])?;
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck
+            .deck
+            .iter_mut()
+            .for_each(|v| v.card.keywords = 0);
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        let frozen_offset = logs
+            .iter()
+            .find_map(|log| match log {
+                FightLog::AddSystem(offset, ctx)
+                    if *offset >= CARD_OFFSET_START
+                        && ctx.system.system_id == SystemId::TriggerFreeze =>
+                {
+                    Some(*offset)
+                }
+                _ => None,
+            })
+            .expect("frozen card");
+        let turn_start = logs
+            .iter()
+            .position(|log| matches!(log, FightLog::PlayerTurn(_)))
+            .expect("player turn");
+        let frozen_index = logs[turn_start..]
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::Draw(offset) => Some(*offset),
+                _ => None,
+            })
+            .position(|offset| offset == frozen_offset)
+            .expect("drawn card");
+        assert!(matches!(
+            battle.run(
+                vec![IterationInput::HandCardUse(
+                    Selection::SingleCard(frozen_index),
+                    Some(0),
+                )],
+                &mut game.controller,
+            ),
+            Err(Error::BattleCardFrozen)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_card_keywords() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemies = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            let enemy = Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?;
+            vec![enemy]
+        };
+        let point = Point::from_xy(1, 0);
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        for (i, offset) in [100, 101].into_iter().enumerate() {
+            let mut card = player_deck.deck[i].clone();
+            card.card.offset = offset;
+            card.card.keywords = 0;
+            player_deck.deck.push(card);
+        }
+        let (retain, ethereal, exhaust) = (
+            player_deck.deck[0].offset(),
+            player_deck.deck[1].offset(),
+            player_deck.deck[2].offset(),
+        );
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE | CARD_KEYWORD_RETAIN;
+        player_deck.deck[1].card.keywords = CARD_KEYWORD_INNATE | CARD_KEYWORD_ETHEREAL;
+        player_deck.deck[2].card.keywords = CARD_KEYWORD_INNATE | CARD_KEYWORD_EXHAUST;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies])?;
+
+        let (_, logs) = battle.start(&mut game.controller)?;
+        let draws = logs
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::Draw(offset) => Some(*offset),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(draws, vec![retain, ethereal, exhaust]);
+
+        let (_, logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(2),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::ExhaustCard(offset) if *offset == exhaust)));
+
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        let exhausted = logs
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::ExhaustCard(offset) => Some(*offset),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(exhausted, vec![ethereal]);
+
+        let (_, logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(0),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::HandCardUse(offset) if *offset == retain)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_card_upgrade() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (_, mut player_deck) = game.new_session(5001, point, None)?;
+        let context = player_deck
+            .deck
+            .iter_mut()
+            .find(|v| v.card.upgrade.is_some())
+            .expect("upgradable card");
+        let mounting = SystemContext::from(context.card.system_pool[0].clone());
+        context.mounting_systems.push(mounting.clone());
+        let offset = context.offset();
+        let mut cost_changed = context.clone();
+        let upgraded = context
+            .card
+            .upgraded(&game.controller.resource_pool, &mut game.controller.rng)?;
+        assert_eq!(Some(upgraded.id), context.card.upgrade);
+        context.upgrade(upgraded.clone());
+        assert_eq!(context.offset(), offset);
+        assert_eq!(context.power_cost, context.card.power_cost);
+        assert_eq!(context.mounting_systems, vec![mounting]);
+
+        // cost raised in the run stays raised after upgrading
+        cost_changed.game_max_power_cost += 1;
+        cost_changed.battle_max_power_cost += 1;
+        cost_changed.upgrade(upgraded);
+        let cost = cost_changed.card.power_cost;
+        assert_eq!(cost_changed.game_max_power_cost, cost + 1);
+        assert_eq!(cost_changed.battle_max_power_cost, cost + 1);
+        assert_eq!(cost_changed.power_cost, cost);
+
+        let context = player_deck
+            .deck
+            .iter()
+            .find(|v| v.card.upgrade.is_none())
+            .expect("plain card");
+        let result = context
+            .card
+            .upgraded(&game.controller.resource_pool, &mut game.controller.rng);
+        assert!(matches!(result, Err(Error::SceneCardNotUpgradable)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_card_services() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        let altar_card = player_deck.deck[0].card.clone();
+        let coordinate = generated::Coordinate::new_builder()
+            .x(1.into())
+            .y(1.into())
+            .build();
+        let removal_count: fn(&WarriorDeckContext) -> u8 = |deck| deck.removal_count;
+        let transform_count: fn(&WarriorDeckContext) -> u8 = |deck| deck.transform_count;
+        for (node, used_count) in [
+            (Node::CardShrine(10, 5), removal_count),
+            (Node::CardAltar(10, 5, vec![altar_card]), transform_count),
+        ] {
+            let mut map = MapSkeleton {
+                id: 0,
+                width: 3,
+                height: 3,
+                skeleton: vec![LevelNode {
+                    visible: true,
+                    point: coordinate.clone().into(),
+                    node,
+                }],
+                player_point: point,
+                difficulty_rules: vec![],
+                modifiers: vec![],
+            };
+            player.gold = 100;
+            for price in [10, 15] {
+                let offset = player_deck.deck.last().expect("deck card").offset();
+                let index = player_deck.deck.len() - 1;
+                let MoveResult::MapLogs(logs) = map.move_to(
+                    &mut player,
+                    &mut player_deck,
+                    Point::from_xy(1, 1),
+                    vec![index],
+                    &mut game.controller,
+                )?
+                else {
+                    panic!("unexpected move result");
+                };
+                assert_eq!(player.gold, 100 - price);
+                player.gold = 100;
+                match logs.as_slice() {
+                    [FightLog::RemoveCard(removed)] => {
+                        assert_eq!(*removed, offset);
+                        assert!(player_deck.deck.iter().all(|v| v.offset() != offset));
+                    }
+                    [FightLog::TransformCard(transformed)] => {
+                        assert_eq!(*transformed, offset);
+                        assert_eq!(player_deck.deck[index].offset(), offset);
+                    }
+                    _ => panic!("unexpected map logs"),
+                }
+                map.player_point = point;
+            }
+            assert_eq!(used_count(&player_deck), 2);
+        }
+
+        // the used count saturates instead of overflowing
+        player_deck.removal_count = u8::MAX;
+        let mut map = MapSkeleton {
+            id: 0,
+            width: 3,
+            height: 3,
+            skeleton: vec![LevelNode {
+                visible: true,
+                point: coordinate.into(),
+                node: Node::CardShrine(10, 0),
+            }],
+            player_point: point,
+            difficulty_rules: vec![],
+            modifiers: vec![],
+        };
+        map.move_to(
+            &mut player,
+            &mut player_deck,
+            Point::from_xy(1, 1),
+            vec![0],
+            &mut game.controller,
+        )?;
+        assert_eq!(player_deck.removal_count, u8::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merchant_stock() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        let item = Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 2,
+            price: 30,
+            system_pool: vec![],
+            slot: EquipmentSlot::Weapon,
+        };
+        let coordinate = generated::Coordinate::new_builder()
+            .x(1.into())
+            .y(1.into())
+            .build();
+        let mut map = MapSkeleton {
+            id: 0,
+            width: 3,
+            height: 3,
+            skeleton: vec![LevelNode {
+                visible: true,
+                point: coordinate.into(),
+                node: Node::ItemMerchant(vec![item.clone()]),
+            }],
+            player_point: point,
+            difficulty_rules: vec![],
+            modifiers: vec![],
+        };
+        player.gold = item.price;
+        player.physique = item.weight;
+        map.move_to(
+            &mut player,
+            &mut player_deck,
+            Point::from_xy(1, 1),
+            vec![0],
+            &mut game.controller,
+        )?;
+        assert_eq!(player.gold, 0);
+        assert!(matches!(&map.skeleton[0].node, Node::ItemMerchant(stock) if stock.is_empty()));
+
+        map.player_point = point;
+        let result = map.move_to(
+            &mut player,
+            &mut player_deck,
+            Point::from_xy(1, 1),
+            vec![0],
+            &mut game.controller,
+        );
+        assert!(matches!(result, Err(Error::SceneUserImportOutOfIndex(0))));
+        assert!(map.player_point == point);
+
+        map.player_point = Point::from_xy(1, 1);
+        let index = player.props_list.len() - 1;
+        let gold = map.sell_items(&mut player, ItemClass::Props, vec![index])?;
+        assert_eq!(gold, item.sell_price());
+        assert_eq!(player.gold, gold);
+        assert_eq!(player.physique, item.weight);
+        assert!(matches!(&map.skeleton[0].node, Node::ItemMerchant(stock) if stock.len() == 1));
+
+        // selling never overflows the gold
+        player.props_list.push(item);
+        player.gold = u16::MAX;
+        let index = player.props_list.len() - 1;
+        map.sell_items(&mut player, ItemClass::Props, vec![index])?;
+        assert_eq!(player.gold, u16::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merchant_transaction() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        let item = Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 2,
+            price: 30,
+            system_pool: vec![],
+            slot: EquipmentSlot::Weapon,
+        };
+        let coordinate = generated::Coordinate::new_builder()
+            .x(1.into())
+            .y(1.into())
+            .build();
+        let mut map = MapSkeleton {
+            id: 0,
+            width: 3,
+            height: 3,
+            skeleton: vec![LevelNode {
+                visible: true,
+                point: coordinate.into(),
+                node: Node::ItemMerchant(vec![item.clone(), item.clone()]),
+            }],
+            player_point: point,
+            difficulty_rules: vec![],
+            modifiers: vec![],
+        };
+        let props_count = player.props_list.len();
+        for (gold, physique, user_imported, expected) in [
+            (60, 4, vec![0, 2], Error::SceneUserImportOutOfIndex(1)),
+            (60, 4, vec![1, 1], Error::SceneUserImportDuplicated(1)),
+            (50, 4, vec![0, 1], Error::SceneMerchantInsufficientGold(1)),
+            (
+                60,
+                3,
+                vec![1, 0],
+                Error::SceneMerchantInsufficientPhysique(1),
+            ),
+        ] {
+            player.gold = gold;
+            player.physique = physique;
+            let result = map.move_to(
+                &mut player,
+                &mut player_deck,
+                Point::from_xy(1, 1),
+                user_imported,
+                &mut game.controller,
+            );
+            assert_eq!(
+                format!("{:?}", result.err()),
+                format!("{:?}", Some(expected))
+            );
+            assert!(map.player_point == point);
+            assert_eq!((player.gold, player.physique), (gold, physique));
+            assert_eq!(player.props_list.len(), props_count);
+            assert!(matches!(&map.skeleton[0].node, Node::ItemMerchant(stock) if stock.len() == 2));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_equipment_slots() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        let equipment = |id, slot, weight, system_pool| Item {
+            id,
+            class: ItemClass::Equipment,
+            quality: 0,
+            weight,
+            price: 0,
+            system_pool,
+            slot,
+        };
+        let armor_up = System {
+            id: 0,
+            system_id: SystemId::InstantArmorUp,
+            args: vec![Value(7)],
+            duration: None,
+            target_type: RequireTarget::Owner,
+        };
+        player.equipped.clear();
+        player.equipment_list = vec![
+            equipment(1, EquipmentSlot::Weapon, 2, vec![]),
+            equipment(2, EquipmentSlot::Weapon, 3, vec![]),
+            equipment(3, EquipmentSlot::Armor, 1, vec![armor_up]),
+        ];
+        player.physique = 0;
+        player.equip(0)?;
+        assert!(matches!(
+            player.equip(0),
+            Err(Error::WarriorEquipmentSlotOccupied)
+        ));
+        player.swap_equipment(0)?;
+        assert_eq!(player.equipped_weight(), 3);
+        assert_eq!(player.equipment_list[1].id, 1);
+        player.equip(0)?;
+        assert_eq!(player.equipped_weight(), 4);
+        player.unequip(EquipmentSlot::Armor)?;
+        assert!(matches!(
+            player.unequip(EquipmentSlot::Armor),
+            Err(Error::WarriorEquipmentSlotEmpty)
+        ));
+        player.equip(1)?;
+        assert_eq!(player.physique, 0);
+
+        // bought equipment takes physique once, and equipping it doesn't count it again
+        let accessory = equipment(4, EquipmentSlot::Accessory, 2, vec![]);
+        let mut map = MapSkeleton {
+            id: 0,
+            width: 3,
+            height: 3,
+            skeleton: vec![LevelNode {
+                visible: true,
+                point: generated::Coordinate::new_builder()
+                    .x(1.into())
+                    .y(1.into())
+                    .build()
+                    .into(),
+                node: Node::ItemMerchant(vec![accessory.clone()]),
+            }],
+            player_point: point,
+            difficulty_rules: vec![],
+            modifiers: vec![],
+        };
+        player.physique = accessory.weight;
+        map.move_to(
+            &mut player,
+            &mut player_deck,
+            Point::from_xy(1, 1),
+            vec![0],
+            &mut game.controller,
+        )?;
+        assert_eq!(player.physique, 0);
+        player.equip(player.equipment_list.len() - 1)?;
+        assert_eq!(player.physique, 0);
+        assert!(player.equipped.iter().any(|v| v.id == accessory.id));
+
+        // initial items take physique, and cannot exceed it
+        let mut warrior = player.warrior.clone();
+        warrior.package_status = vec![equipment(5, EquipmentSlot::Weapon, 3, vec![])];
+        let (initial, _) = WarriorContext::new(warrior.clone(), None)?;
+        assert_eq!(initial.physique, warrior.physique - 3);
+        warrior.package_status[0].weight = warrior.physique + 1;
+        assert!(matches!(
+            WarriorContext::new(warrior, None),
+            Err(Error::WarriorInsufficientPhysique)
+        ));
+
+        let enemies = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            vec![Enemy::randomized(
+                resource_pool,
+                enemy,
+                &mut game.controller.rng,
+            )?]
+        };
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies])?;
+        let (_, logs) = battle.start(&mut game.controller)?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemArmorUp(_, 7))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted_loot() -> eyre::Result<()> {
+        let item = |id, quality| Item {
+            id,
+            class: ItemClass::Props,
+            quality,
+            weight: 1,
+            price: 0,
+            system_pool: vec![],
+            slot: EquipmentSlot::Weapon,
+        };
+        let roll = |rarity, seed| {
+            let mut rng = SporeRng::new(seed);
+            (0..1000)
+                .map(|_| {
+                    let pool = vec![item(1, 0), item(2, 4)];
+                    randomized_weighted_selection(pool, 1, |v| v.drop_weight(rarity), &mut rng)[0]
+                        .id
+                })
+                .collect::<Vec<_>>()
+        };
+        let rare_count = |ids: &Vec<u16>| ids.iter().filter(|id| **id == 2).count();
+        let common_rolls = roll(0, 10086);
+        let rare_rolls = roll(100, 10086);
+        assert_eq!(common_rolls, roll(0, 10086));
+        assert!(rare_count(&common_rolls) < 500);
+        assert!(rare_count(&rare_rolls) > rare_count(&common_rolls));
+
+        let mut rng = SporeRng::new(10086);
+        let picked = randomized_weighted_selection(
+            vec![item(1, 0), item(2, 4)],
+            3,
+            |v| v.drop_weight(0),
+            &mut rng,
+        );
+        assert_eq!(picked.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_boss_phases() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let mut enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let enraged = System {
+            id: 0,
+            system_id: SystemId::InstantArmorUp,
+            args: vec![Value(9)],
+            duration: None,
+            target_type: RequireTarget::Owner,
+        };
+        enemy.rank = EnemyRank::Boss;
+        enemy.hp = 8;
+        enemy.armor = 0;
+        enemy.shield = 0;
+        enemy.phases = vec![EnemyPhase {
+            hp_percent: 50,
+            strategy: ActionStrategy {
+                random_select: false,
+                actions: vec![Action {
+                    random_select: false,
+                    system_pool: vec![enraged],
+                }],
+                rules: vec![],
+            },
+        }];
+        let point = Point::from_xy(1, 0);
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        battle.start(&mut game.controller)?;
+        let (_, logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(0),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::EnemyPhaseChange(1, 1))));
+        let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemArmorUp(1, 9))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_conditional_enemy_actions() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let mut enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let rule = |armor, cooldown, conditions| ActionRule {
+            action: Action {
+                random_select: false,
+                system_pool: vec![System {
+                    id: 0,
+                    system_id: SystemId::InstantArmorUp,
+                    args: vec![Value(armor)],
+                    duration: None,
+                    target_type: RequireTarget::Owner,
+                }],
+            },
+            weight: 1,
+            cooldown,
+            conditions,
+        };
+        enemy.strategy.rules = vec![
+            rule(1, 1, vec![ActionCondition::OwnHpBelow(50)]),
+            rule(2, 0, vec![ActionCondition::RoundMultipleOf(2)]),
+            rule(3, 0, vec![ActionCondition::PlayerShieldAbove(5)]),
+        ];
+        let (mut player, _) = game.new_session(5001, Point::from_xy(1, 0), None)?;
+        let mut rng = SporeRng::new(10086);
+        let mut enemy = EnemyContext::new(enemy, 1);
+        let armor_of = |effects: Vec<System>| match effects.as_slice() {
+            [System {
+                system_id: SystemId::InstantArmorUp,
+                args,
+                ..
+            }] => Some(args[0].0),
+            _ => None,
+        };
+
+        player.shield = 0;
+        assert_eq!(armor_of(enemy.pop_action(&player, 1, &mut rng)?), None);
+        assert_eq!(armor_of(enemy.pop_action(&player, 2, &mut rng)?), Some(2));
+        enemy.hp = 1;
+        assert_eq!(armor_of(enemy.pop_action(&player, 1, &mut rng)?), Some(1));
+        assert_eq!(armor_of(enemy.pop_action(&player, 1, &mut rng)?), None);
+        assert_eq!(armor_of(enemy.pop_action(&player, 1, &mut rng)?), Some(1));
+        enemy.hp = enemy.enemy.hp;
+        player.shield = 10;
+        assert_eq!(armor_of(enemy.pop_action(&player, 1, &mut rng)?), Some(3));
+
+        let rolls = |seed| {
+            let mut rng = SporeRng::new(seed);
+            let mut enemy = enemy.clone();
+            enemy.hp = 1;
+            (0..20)
+                .map(|_| armor_of(enemy.pop_action(&player, 2, &mut rng).unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(7), rolls(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_enemy_summon() -> eyre::Result<()> {
+        let summon_battle = |count: u16,
+                             rules: &[DifficultyRule],
+                             modifiers: &[ChallengeModifier]|
+         -> eyre::Result<_> {
+            let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+            let mut enemy = {
+                let resource_pool = &game.controller.resource_pool;
+                let enemy = resource_pool.enemy_pool().get_unchecked(0);
+                Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+            };
+            let summon = System {
+                id: 0,
+                system_id: SystemId::InstantSummonEnemies,
+                args: vec![Value(enemy.id), Value(count), Value(2)],
+                duration: None,
+                target_type: RequireTarget::Owner,
+            };
+            enemy.hp = 1;
+            enemy.armor = 0;
+            enemy.shield = 0;
+            enemy.strategy = ActionStrategy {
+                random_select: false,
+                actions: vec![Action {
+                    random_select: false,
+                    system_pool: vec![summon],
+                }],
+                rules: vec![],
+            };
+            let point = Point::from_xy(1, 0);
+            let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+            player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE | CARD_KEYWORD_RETAIN;
+            let mut map = MapSkeleton {
+                id: 0,
+                width: 3,
+                height: 3,
+                skeleton: vec![LevelNode {
+                    visible: true,
+                    point: generated::Coordinate::new_builder()
+                        .x(1.into())
+                        .y(1.into())
+                        .build()
+                        .into(),
+                    node: Node::Enemy(vec![vec![enemy]]),
+                }],
+                player_point: point,
+                difficulty_rules: vec![],
+                modifiers: vec![],
+            };
+            map.apply_difficulty(rules);
+            map.apply_modifiers(modifiers);
+            let MoveResult::Fight(mut battle) = map.move_to(
+                &mut player,
+                &mut player_deck,
+                Point::from_xy(1, 1),
+                vec![],
+                &mut game.controller,
+            )?
+            else {
+                panic!("unexpected move result");
+            };
+            battle.start(&mut game.controller)?;
+            let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+            Ok((game, battle, logs))
+        };
+
+        // enemies beyond the alive cap are dropped before being randomized
+        let (mut capped_game, _, _) = summon_battle(1, &[], &[])?;
+        let (mut game, mut battle, logs) = summon_battle(3, &[], &[])?;
+        assert_eq!(
+            game.controller.rng.next_u32(),
+            capped_game.controller.rng.next_u32()
+        );
+        let summoned = logs
+            .iter()
+            .filter_map(|log| match log {
+                FightLog::SummonEnemy(offset) => Some(*offset),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(summoned, vec![2]);
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::Snapshot(_, enemies) if enemies.len() == 2)));
+
+        let (output, logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(0),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemDamage(_, 1, _))));
+        assert_ne!(output, IterationOutput::GameWin);
+
+        // summoned enemies are scaled by the same difficulty rules and modifiers as the map
+        let summoned_enemy = |logs: &[FightLog]| {
+            logs.iter().find_map(|log| match log {
+                FightLog::Snapshot(_, enemies) if enemies.len() == 2 => Some(enemies[1].clone()),
+                _ => None,
+            })
+        };
+        let rules = [
+            DifficultyRule {
+                level: 1,
+                class: DifficultyClass::EnemyHpPercentUp,
+                value: 50,
+            },
+            DifficultyRule {
+                level: 2,
+                class: DifficultyClass::EnemyAttackUp,
+                value: 2,
+            },
+        ];
+        let modifiers = [ChallengeModifier {
+            id: 0,
+            class: ModifierClass::EnemyHpPercentUp,
+            value: 100,
+        }];
+        let (_, _, logs) = summon_battle(3, &[], &[])?;
+        let base = summoned_enemy(&logs).expect("summoned enemy");
+        let (_, _, logs) = summon_battle(3, &rules, &modifiers)?;
+        let scaled = summoned_enemy(&logs).expect("summoned enemy");
+        assert_eq!(scaled.hp, base.hp * 150 / 100 * 2);
+        assert_eq!(scaled.attack, base.attack + 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_system_targets() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let system = |system_id, target_type| System {
+            id: 0,
+            system_id,
+            args: vec![Value(1)],
+            duration: None,
+            target_type,
+        };
+        let idle = ActionStrategy {
+            random_select: false,
+            actions: vec![Action {
+                random_select: false,
+                system_pool: vec![],
+            }],
+            rules: vec![],
+        };
+        let mut enemies = vec![];
+        for hp in [30, 20, 10] {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            let mut enemy = Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?;
+            enemy.hp = hp;
+            enemy.strategy = idle.clone();
+            enemies.push(enemy);
+        }
+        let shielded = |logs: &[FightLog]| {
+            let mut offsets = logs
+                .iter()
+                .filter_map(|log| match log {
+                    FightLog::SystemShieldUp(offset, _) => Some(*offset),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            offsets.sort();
+            offsets
+        };
+        let point = Point::from_xy(1, 0);
+        for target in [
+            RequireTarget::Owner,
+            RequireTarget::Opponent,
+            RequireTarget::RandomOpponent,
+            RequireTarget::AllOpponents,
+            RequireTarget::AllCharactors,
+            RequireTarget::RandomAlly,
+            RequireTarget::AllAllies,
+            RequireTarget::LowestHpAlly,
+        ] {
+            // player view, the second enemy is selected by index
+            let (mut player, player_deck) = game.new_session(5001, point, None)?;
+            player.props_list = vec![Item {
+                id: 0,
+                class: ItemClass::Props,
+                quality: 0,
+                weight: 0,
+                price: 0,
+                system_pool: vec![system(SystemId::InstantShieldUp, target)],
+                slot: EquipmentSlot::Weapon,
+            }];
+            let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies.clone()])?;
+            battle.start(&mut game.controller)?;
+            let (_, logs) = battle.run(
+                vec![IterationInput::ItemUse(Selection::Item(0), Some(1))],
+                &mut game.controller,
+            )?;
+            let offsets = shielded(&logs);
+            match target {
+                RequireTarget::Opponent => assert_eq!(offsets, vec![2]),
+                RequireTarget::RandomOpponent => {
+                    assert!(offsets.len() == 1 && (1..=3).contains(&offsets[0]))
+                }
+                RequireTarget::AllOpponents => assert_eq!(offsets, vec![1, 2, 3]),
+                RequireTarget::AllCharactors => assert_eq!(offsets, vec![0, 1, 2, 3]),
+                _ => assert_eq!(offsets, vec![0]),
+            }
+
+            // enemy view, only the second enemy acts
+            let (player, player_deck) = game.new_session(5001, point, None)?;
+            let mut casters = enemies.clone();
+            casters[1].strategy.actions[0].system_pool =
+                vec![system(SystemId::InstantShieldUp, target)];
+            let mut battle = MapBattlePVE::create(player, player_deck, vec![casters])?;
+            battle.start(&mut game.controller)?;
+            let (_, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+            let offsets = shielded(&logs);
+            match target {
+                RequireTarget::Owner => assert_eq!(offsets, vec![2]),
+                RequireTarget::AllCharactors => assert_eq!(offsets, vec![0, 1, 2, 3]),
+                RequireTarget::RandomAlly => {
+                    assert!(offsets.len() == 1 && (1..=3).contains(&offsets[0]))
+                }
+                RequireTarget::AllAllies => assert_eq!(offsets, vec![1, 2, 3]),
+                RequireTarget::LowestHpAlly => assert_eq!(offsets, vec![3]),
+                _ => assert_eq!(offsets, vec![0]),
+            }
+
+            // card view, card systems are called on the card itself at the start
+            let (player, mut player_deck) = game.new_session(5001, point, None)?;
+            let special_offset = player_deck.special_card.offset();
+            player_deck.special_card.card.system_pool =
+                vec![system(SystemId::InstantPowerCostDown, target)];
+            let mut battle = MapBattlePVE::create(player, player_deck, vec![enemies.clone()])?;
+            let (_, logs) = battle.start(&mut game.controller)?;
+            assert!(logs
+                .iter()
+                .any(|log| matches!(log, FightLog::SystemPowerCostChange(offset, _) if *offset == special_offset)));
+            assert!(shielded(&logs).is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fight_stream() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let point = Point::from_xy(1, 0);
+        let (player, player_deck) = game.new_session(5001, point, None)?;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        let (_, mut logs) = battle.start(&mut game.controller)?;
+        let (output, mut turn_logs) = battle.run(
+            vec![IterationInput::EnemyTurn, IterationInput::EnemyTurn],
+            &mut game.controller,
+        )?;
+        logs.append(&mut turn_logs);
+
+        let raw_stream = encode_fight_stream(&output, &logs);
+        let (decoded_output, decoded_logs) = decode_fight_stream(&raw_stream)?;
+        assert_eq!(output, decoded_output);
+        assert_eq!(format!("{logs:?}"), format!("{decoded_logs:?}"));
+        assert!(raw_stream.len() < serde_json::to_vec(&logs)?.len());
+
+        let mut raw_stream = raw_stream;
+        let version_index = raw_stream.len() - rlp::Rlp::new(&raw_stream).payload_info()?.value_len;
+        raw_stream[version_index] = FIGHT_STREAM_VERSION + 1;
+        assert!(matches!(
+            decode_fight_stream(&raw_stream),
+            Err(Error::BattleStreamVersionUnsupported)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_battle_observer() -> eyre::Result<()> {
+        #[derive(Default)]
+        struct Recorder {
+            logs: Vec<FightLog>,
+            system_calls: Vec<(usize, SystemId, Vec<usize>)>,
+            enemy_hp_changes: Vec<(u16, u16)>,
+        }
+
+        struct Observer(Rc<RefCell<Recorder>>);
+
+        impl BattleObserver for Observer {
+            fn on_log(&mut self, log: &FightLog) {
+                self.0.borrow_mut().logs.push(log.clone());
+            }
+
+            fn on_system_call(&mut self, caster: usize, ctx: &SystemContext, targets: &[usize]) {
+                self.0.borrow_mut().system_calls.push((
+                    caster,
+                    ctx.system.system_id,
+                    targets.to_vec(),
+                ));
+            }
+
+            fn on_state_change(
+                &mut self,
+                _: &SystemContext,
+                before: &BattleState,
+                after: &BattleState,
+            ) {
+                let (before, after) = (before.opponents[0].hp, after.opponents[0].hp);
+                if before != after {
+                    self.0.borrow_mut().enemy_hp_changes.push((before, after));
+                }
+            }
+        }
+
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let mut enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        enemy.armor = 0;
+        enemy.shield = 0;
+        let point = Point::from_xy(1, 0);
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        let recorder = Rc::new(RefCell::new(Recorder::default()));
+        battle.add_observer(Box::new(Observer(recorder.clone())));
+        let (_, mut logs) = battle.start(&mut game.controller)?;
+        let (_, mut turn_logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(0),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        logs.append(&mut turn_logs);
+
+        let recorder = recorder.borrow();
+        assert_eq!(format!("{logs:?}"), format!("{:?}", recorder.logs));
+        assert!(recorder
+            .system_calls
+            .iter()
+            .any(|(caster, system_id, targets)| *caster == 0
+                && *system_id == SystemId::InstantDamage
+                && targets == &vec![1]));
+        assert!(recorder
+            .enemy_hp_changes
+            .iter()
+            .any(|(before, after)| after < before));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_by_step_battle() -> eyre::Result<()> {
+        let fight = |step_mode: bool| -> eyre::Result<(Vec<FightLog>, usize)> {
+            let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+            let enemy = {
+                let resource_pool = &game.controller.resource_pool;
+                let enemy = resource_pool.enemy_pool().get_unchecked(0);
+                Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+            };
+            let point = Point::from_xy(1, 0);
+            let (player, mut player_deck) = game.new_session(5001, point, None)?;
+            player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+            let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+            battle.set_step_mode(step_mode);
+            let (_, mut logs) = battle.start(&mut game.controller)?;
+            assert!(battle.is_settled());
+            let mut steps = 0;
+            for operation in [
+                IterationInput::HandCardUse(Selection::SingleCard(0), Some(0)),
+                IterationInput::EnemyTurn,
+            ] {
+                let (_, mut operation_logs) = battle.run(vec![operation], &mut game.controller)?;
+                logs.append(&mut operation_logs);
+                if step_mode {
+                    assert!(battle.current_instruction().is_some() || !battle.is_settled());
+                }
+                while !battle.is_settled() {
+                    let current = battle.current_instruction().map(|v| v.system.system_id);
+                    let (_, mut step_logs) = battle.step(&mut game.controller)?;
+                    if let (Some(system_id), Some(FightLog::CallSystem(_, ctx))) =
+                        (current, step_logs.first())
+                    {
+                        assert!(ctx.system.system_id == system_id);
+                    }
+                    logs.append(&mut step_logs);
+                    steps += 1;
+                }
+            }
+            assert!(matches!(
+                battle.step(&mut game.controller),
+                Err(Error::BattleInstructionEmpty)
+            ));
+            Ok((logs, steps))
+        };
+        let (logs, steps) = fight(false)?;
+        let (stepped_logs, stepped_steps) = fight(true)?;
+        assert_eq!(steps, 0);
+        assert!(stepped_steps > 2);
+        // card offsets are allocated globally, so only compare the log codes and damages
+        let digest = |logs: &[FightLog]| {
+            logs.iter()
+                .map(|log| match log {
+                    FightLog::SystemDamage(source, target, damage) => {
+                        (u16::from(log), *source, *target, *damage)
+                    }
+                    _ => (u16::from(log), 0, 0, 0),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(digest(&logs), digest(&stepped_logs));
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_player_actions() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let point = Point::from_xy(1, 0);
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+        player.props_list = vec![Item {
+            id: 0,
+            class: ItemClass::Props,
+            quality: 0,
+            weight: 0,
+            price: 0,
+            system_pool: vec![System {
+                id: 0,
+                system_id: SystemId::InstantDrawCards,
+                args: vec![Value(1)],
+                duration: None,
+                target_type: RequireTarget::Owner,
+            }],
+            slot: EquipmentSlot::Weapon,
+        }];
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy.clone()]])?;
+        let (_, logs) = battle.start(&mut game.controller)?;
+        let FightLog::Snapshot(player, enemies) = logs[1].clone() else {
+            panic!("missing snapshot");
+        };
+        assert!(matches!(
+            battle.undo(&mut game.controller),
+            Err(Error::BattleUndoUnavailable)
+        ));
+
+        let card_use = || IterationInput::HandCardUse(Selection::SingleCard(0), Some(0));
+        let (_, logs) = battle.run(vec![card_use()], &mut game.controller)?;
+        assert_eq!(battle.undo_count(), 1);
+        let FightLog::Snapshot(reverted_player, reverted_enemies) =
+            battle.undo(&mut game.controller)?
+        else {
+            panic!("missing snapshot");
+        };
+        assert_eq!(reverted_player, player);
+        assert_eq!(reverted_enemies[0].hp, enemies[0].hp);
+        assert_eq!(battle.undo_count(), 0);
+        let (_, replayed_logs) = battle.run(vec![card_use()], &mut game.controller)?;
+        assert_eq!(format!("{logs:?}"), format!("{replayed_logs:?}"));
+
+        // drawing cards reveals the deck, so the history is dropped
+        let (_, logs) = battle.run(
+            vec![IterationInput::ItemUse(Selection::Item(0), None)],
+            &mut game.controller,
+        )?;
+        assert!(logs.iter().any(|log| matches!(log, FightLog::Draw(_))));
+        assert_eq!(battle.undo_count(), 0);
+
+        battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert!(matches!(
+            battle.undo(&mut game.controller),
+            Err(Error::BattleUndoUnavailable)
+        ));
+
+        // a failed action is rolled back instead of leaving the used card out of hand
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck.deck.iter_mut().for_each(|card| {
+            card.card.power_cost = u8::MAX;
+            card.power_cost = u8::MAX;
+        });
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        let (_, logs) = battle.start(&mut game.controller)?;
+        let hand_count = logs
+            .iter()
+            .filter(|log| matches!(log, FightLog::Draw(_)))
+            .count();
+        let mut rng = game.controller.rng.clone();
+        assert!(matches!(
+            battle.run(vec![card_use()], &mut game.controller),
+            Err(Error::BattlePowerInsufficient)
+        ));
+        assert_eq!(battle.undo_count(), 0);
+        assert_eq!(game.controller.rng.next_u32(), rng.next_u32());
+        assert!(battle
+            .peak_target(Selection::SingleCard(hand_count - 1))
+            .is_ok());
+        assert!(matches!(
+            battle.peak_target(Selection::SingleCard(hand_count)),
+            Err(Error::BattleSelectionError)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_battle_limits() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let point = Point::from_xy(1, 0);
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+        let mut battle = MapBattlePVE::create(player, player_deck, vec![vec![enemy]])?;
+        assert!(matches!(
+            battle.set_limits(0, 1),
+            Err(Error::BattleInvalidLimits)
+        ));
+        battle.set_limits(2, 1)?;
+        battle.start(&mut game.controller)?;
+
+        let card_use = || IterationInput::HandCardUse(Selection::SingleCard(0), Some(0));
+        battle.run(vec![card_use()], &mut game.controller)?;
+        assert!(matches!(
+            battle.run(vec![card_use()], &mut game.controller),
+            Err(Error::BattleTurnActionsExceeded)
+        ));
+        let (output, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert_eq!(output, IterationOutput::Continue);
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::PlayerTurn(2))));
+        let (output, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert_eq!(output, IterationOutput::GameTimeout);
+        assert!(logs.iter().any(|log| matches!(log, FightLog::GameOver)));
+        assert!(!logs
+            .iter()
+            .any(|log| matches!(log, FightLog::PlayerTurn(3))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_enemy_waves() -> eyre::Result<()> {
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let mut weak_enemy = enemy.clone();
+        weak_enemy.hp = 1;
+        weak_enemy.armor = 0;
+        weak_enemy.shield = 0;
+        let point = Point::from_xy(1, 0);
+        let (player, mut player_deck) = game.new_session(5001, point, None)?;
+        assert!(matches!(
+            MapBattlePVE::create(player.clone(), player_deck.clone(), vec![]),
+            Err(Error::BattleEnemyWaveEmpty)
+        ));
+        assert!(matches!(
+            MapBattlePVE::create(
+                player.clone(),
+                player_deck.clone(),
+                vec![vec![], vec![enemy.clone()]]
+            ),
+            Err(Error::BattleEnemyWaveEmpty)
+        ));
+        player_deck.deck[0].card.keywords = CARD_KEYWORD_INNATE;
+        let waves = vec![vec![weak_enemy], vec![enemy.clone(), enemy]];
+        let mut battle = MapBattlePVE::create(player, player_deck, waves)?;
+        assert_eq!(battle.wave(), 1);
+        assert_eq!(battle.remaining_waves(), 1);
+        battle.start(&mut game.controller)?;
+
+        let (output, logs) = battle.run(
+            vec![IterationInput::HandCardUse(
+                Selection::SingleCard(0),
+                Some(0),
+            )],
+            &mut game.controller,
+        )?;
+        assert_eq!(output, IterationOutput::Continue);
+        assert!(!logs.iter().any(|log| matches!(log, FightLog::GameOver)));
+        let wave_index = logs
+            .iter()
+            .position(|log| matches!(log, FightLog::EnemyWave(2)))
+            .expect("wave transition");
+        let FightLog::Snapshot(player, enemies) = &logs[wave_index + 1] else {
+            panic!("missing snapshot after wave transition");
+        };
+        assert_eq!(
+            enemies.iter().map(|v| v.offset()).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(enemies.iter().all(|v| v.hp == v.enemy.hp));
+        assert_eq!(player.hp, player.max_hp);
+        // the rest of the card still settles on player after the transition
+        assert!(logs[wave_index..]
+            .iter()
+            .any(|log| matches!(log, FightLog::SystemDamage(_, 0, _))));
+        assert_eq!(battle.wave(), 2);
+        assert_eq!(battle.remaining_waves(), 0);
+
+        let (output, logs) = battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+        assert_eq!(output, IterationOutput::Continue);
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::PlayerTurn(2))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pvp_replay() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let (raw_opponent, raw_opponent_deck) = {
+            let mut game = Game::new(&RAW_RESOURCE_POOL, 20000, 0)?;
+            let (opponent, opponent_deck) = game.new_session(5001, point, None)?;
+            (rlp::encode(&opponent), rlp::encode(&opponent_deck))
+        };
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (player, player_deck) = game.new_session(5001, point, None)?;
+
+        let fight = |seed: u64| -> eyre::Result<(IterationOutput, Vec<Vec<u8>>)> {
+            let mut game = Game::new(&RAW_RESOURCE_POOL, seed, 0)?;
+            game.new_session(5001, point, None)?;
+            let (opponent, opponent_deck) = decode_opponent(&raw_opponent, &raw_opponent_deck)?;
+            let mut battle =
+                MapBattlePVP::create(player.clone(), player_deck.clone(), opponent, opponent_deck)?;
+            battle.set_max_round(5)?;
+            assert_eq!(battle.opponent().offset, OPPONENT_OFFSET);
+            let mut player_deck = player_deck.clone();
+            let player_offsets = player_deck
+                .collect_cards()
+                .into_iter()
+                .map(|v| v.offset())
+                .collect::<Vec<_>>();
+            assert!(battle
+                .opponent_deck()
+                .deck
+                .iter()
+                .all(|v| !player_offsets.contains(&v.offset())));
+
+            let (mut output, logs) = battle.start(&mut game.controller)?;
+            let mut streams = vec![encode_fight_stream(&output, &logs)];
+            while output == IterationOutput::Continue {
+                let logs;
+                (output, logs) =
+                    battle.run(vec![IterationInput::EnemyTurn], &mut game.controller)?;
+                streams.push(encode_fight_stream(&output, &logs));
+            }
+            battle.destroy()?;
+            Ok((output, streams))
+        };
+
+        let (output, streams) = fight(10000)?;
+        assert!(matches!(
+            output,
+            IterationOutput::GameWin | IterationOutput::GameLose | IterationOutput::GameTimeout
+        ));
+        let logs = streams
+            .iter()
+            .map(|v| decode_fight_stream(v).map(|(_, logs)| logs))
+            .collect::<Result<Vec<_>, _>>()?
+            .concat();
+        assert!(logs.iter().any(|log| matches!(log, FightLog::EnemyTurn(1))));
+        assert!(logs
+            .iter()
+            .any(|log| matches!(log, FightLog::CallSystem(OPPONENT_OFFSET, _))));
+
+        // replaying the same inputs reproduces the same battle
+        let (replay_output, replay_streams) = fight(10000)?;
+        assert_eq!(output, replay_output);
+        assert_eq!(streams, replay_streams);
+        Ok(())
+    }
+
+    #[test]
+    fn test_daily_challenge() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let game = Game::new_daily_challenge(&RAW_RESOURCE_POOL, 20000)?;
+        let same_day_game = Game::new_daily_challenge(&RAW_RESOURCE_POOL, 20000)?;
+        assert_eq!(game.modifiers.len(), DAILY_MODIFIER_COUNT as usize);
+        assert_eq!(
+            format!("{:?}", game.modifiers),
+            format!("{:?}", same_day_game.modifiers)
+        );
+        assert_eq!(
+            format!("{:?}", game.map),
+            format!("{:?}", same_day_game.map)
+        );
+
+        // the recorded replay alone rebuilds the same challenge
+        let replay = game
+            .record_replay(generated::GameReplay::new_builder())
+            .build();
+        let challenge_day: u64 = replay.challenge_day().into();
+        let rebuilt_game = Game::new_daily_challenge(&RAW_RESOURCE_POOL, challenge_day)?;
+        rebuilt_game.check_replay_modifiers(&replay)?;
+        assert_eq!(format!("{:?}", game.map), format!("{:?}", rebuilt_game.map));
+        let ids = game.modifiers.iter().map(|v| v.id).collect::<Vec<_>>();
+        let modified_replay = replay
+            .clone()
+            .as_builder()
+            .modifiers(ids[..1].to_vec().into())
+            .build();
+        assert!(matches!(
+            game.check_replay_modifiers(&modified_replay),
+            Err(Error::ChallengeModifierMismatch)
+        ));
+        let modified_replay = replay.as_builder().challenge_day(20001u64.into()).build();
+        assert!(matches!(
+            game.check_replay_modifiers(&modified_replay),
+            Err(Error::ChallengeDayMismatch)
+        ));
+
+        // find the days rolling each kind of modifier
+        let find_day = |class: ModifierClass| {
+            (0..100u64).find_map(|day| {
+                let game = Game::new_daily_challenge(&RAW_RESOURCE_POOL, day).ok()?;
+                game.modifiers
+                    .iter()
+                    .any(|v| v.class == class)
+                    .then_some(game)
+            })
+        };
+        let mut game = find_day(ModifierClass::RandomPotion).expect("random potion day");
+        let (player, player_deck) = game.new_session(5001, point, None)?;
+        assert!(game.potion.is_some());
+        assert_eq!(player_deck.deck.len(), player.warrior.deck_status.len() + 1);
+        let game = find_day(ModifierClass::NoMerchant).expect("no merchant day");
+        assert!(!game
+            .map
+            .skeleton
+            .iter()
+            .any(|level| matches!(level.node, Node::ItemMerchant(_) | Node::CardMerchant(_))));
+
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let enemy = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            Enemy::randomized(resource_pool, enemy, &mut game.controller.rng)?
+        };
+        let hp = enemy.hp;
+        game.map.skeleton[0].node = Node::Enemy(vec![vec![enemy]]);
+        game.map.apply_modifiers(&[ChallengeModifier {
+            id: 0,
+            class: ModifierClass::EnemyHpPercentUp,
+            value: 20,
+        }]);
+        let Node::Enemy(waves) = &game.map.skeleton[0].node else {
+            panic!("enemy node expected");
+        };
+        assert_eq!(waves[0][0].hp, hp * 120 / 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_difficulty() -> eyre::Result<()> {
+        let point = Point::from_xy(1, 0);
+        let mut game = Game::new(&RAW_RESOURCE_POOL, 10000, 0)?;
+        let (mut player, mut player_deck) = game.new_session(5001, point, None)?;
+        let mut hard_game = Game::new(&RAW_RESOURCE_POOL, 10000, 5)?;
+        let (_, hard_player_deck) = hard_game.new_session(5001, point, None)?;
+        assert!(game.difficulty_rules.is_empty());
+        assert_eq!(hard_game.difficulty_rules.len(), 5);
+        assert!(matches!(
+            Game::new(&RAW_RESOURCE_POOL, 10000, 6),
+            Err(Error::DifficultyOutOfRange)
+        ));
+        assert_eq!(hard_player_deck.deck.len(), player_deck.deck.len() + 1);
+        assert_eq!(player_deck.deck.len(), player.warrior.deck_status.len());
+        assert!(hard_player_deck.deck.iter().any(|v| v.card.id == 102));
+
+        let (enemy, card) = {
+            let resource_pool = &game.controller.resource_pool;
+            let enemy = resource_pool.enemy_pool().get_unchecked(0);
+            let card = resource_pool.card_pool().get_unchecked(0);
+            let rng = &mut game.controller.rng;
+            (
+                Enemy::randomized(resource_pool, enemy, rng)?,
+                Card::randomized(resource_pool, card, rng)?,
+            )
+        };
+        let (hp, attack, price) = (enemy.hp, enemy.attack, card.price);
+        game.map.skeleton[0].node = Node::Enemy(vec![vec![enemy.clone()], vec![enemy]]);
+        game.map.skeleton[1].node = Node::RecoverPoint(50);
+        game.map.skeleton[2].node = Node::CardMerchant(vec![card]);
+        game.map.apply_difficulty(&hard_game.difficulty_rules);
+        let Node::Enemy(waves) = &game.map.skeleton[0].node else {
+            panic!("enemy node expected");
+        };
+        for wave in waves {
+            assert_eq!(wave[0].hp, hp * 110 / 100);
+            assert_eq!(wave[0].attack, attack + 2);
+        }
+        assert!(matches!(game.map.skeleton[1].node, Node::RecoverPoint(40)));
+        let Node::CardMerchant(cards) = &game.map.skeleton[2].node else {
+            panic!("card merchant node expected");
+        };
+        assert_eq!(cards[0].price, price * 125 / 100);
+
+        // recover point heals by the percent left after the difficulty rules
+        let max_hp = player.warrior.hp;
+        for (rules, percent) in [
+            (&game.difficulty_rules, 50),
+            (&hard_game.difficulty_rules, 40),
+        ] {
+            let mut map = MapSkeleton {
+                id: 0,
+                width: 3,
+                height: 3,
+                skeleton: vec![LevelNode {
+                    visible: true,
+                    point: generated::Coordinate::new_builder()
+                        .x(1.into())
+                        .y(1.into())
+                        .build()
+                        .into(),
+                    node: Node::RecoverPoint(50),
+                }],
+                player_point: point,
+                difficulty_rules: vec![],
+                modifiers: vec![],
+            };
+            map.apply_difficulty(rules);
+            player.hp = 1;
+            let MoveResult::MapLogs(logs) = map.move_to(
+                &mut player,
+                &mut player_deck,
+                Point::from_xy(1, 1),
+                vec![],
+                &mut game.controller,
+            )?
+            else {
+                panic!("unexpected move result");
+            };
+            let recovered = max_hp * percent / 100;
+            assert!(recovered > 0);
+            assert_eq!(player.hp, 1 + recovered);
+            assert!(matches!(logs.as_slice(), [FightLog::RecoverHp(hp)] if *hp == recovered));
+        }
+
+        let replay = generated::GameReplay::new_builder()
+            .difficulty(5u8.into())
+            .build();
+        let snapshot = generated::GameSnapshot::new_builder()
+            .difficulty(5u8.into())
+            .build();
+        hard_game.check_difficulty(&replay, &snapshot)?;
+        assert!(matches!(
+            game.check_difficulty(&replay, &snapshot),
+            Err(Error::DifficultyMismatch)
+        ));
+        Ok(())
+    }
 }